@@ -1,7 +1,6 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 use image::ImageReader;
 use rs_math::tensor::Tensor;
-use std::mem::drop;
 
 fn img_to_vec(path: &str) -> Vec<u8> {
     let img = ImageReader::open(path)
@@ -26,22 +25,21 @@ fn img_to_vec(path: &str) -> Vec<u8> {
 }
 
 fn bench_mul(c: &mut Criterion) {
-    println!("#################### Bench: tensor::mul ####################");
+    println!("#################### Bench: tensor::matmul ####################");
 
     /* Bench: (100x100x3) * (100x3x3) = (100x100x3) */
     println!("##### Bench: (100x100x3) * (100x3x3) = (100x100x3) #####");
     let img = img_to_vec("/usr/local/dev/rs_math/rs_math/benches/input/matrix_imgs/100x100x3.png");
-    let tensor_a = Tensor::from_shape(&[100, 100, 3], &img).unwrap();
+    let tensor_a = Tensor::from_shape(&[100, 100, 3], img).unwrap();
     println!("Shape (A): {:?}", tensor_a.shape());
 
     let img = img_to_vec("/usr/local/dev/rs_math/rs_math/benches/input/matrix_imgs/100x3x3.png");
-    let tensor_b = Tensor::from_shape(&[100, 3, 3], &img).unwrap();
+    let tensor_b = Tensor::from_shape(&[100, 3, 3], img).unwrap();
     println!("Shape (B): {:?}", tensor_b.shape());
-    drop(img);
 
-    c.bench_function("tensor::mul: (100x100x3) * (100x3x3) = (100x100x3)", |b| {
+    c.bench_function("tensor::matmul: (100x100x3) * (100x3x3) = (100x100x3)", |b| {
         b.iter(|| {
-            tensor_a.mul(&tensor_b.view()).unwrap();
+            tensor_a.matmul(&tensor_b.view()).unwrap();
         })
     });
 
@@ -49,19 +47,18 @@ fn bench_mul(c: &mut Criterion) {
     println!("##### Bench: (1000x1000x3) * (1000x3x3) = (1000x1000x3) #####");
     let img =
         img_to_vec("/usr/local/dev/rs_math/rs_math/benches/input/matrix_imgs/1000x1000x3.png");
-    let tensor_a = Tensor::from_shape(&[1000, 1000, 3], &img).unwrap();
+    let tensor_a = Tensor::from_shape(&[1000, 1000, 3], img).unwrap();
     println!("Shape (A): {:?}", tensor_a.shape());
 
     let img = img_to_vec("/usr/local/dev/rs_math/rs_math/benches/input/matrix_imgs/1000x3x3.png");
-    let tensor_b = Tensor::from_shape(&[1000, 3, 3], &img).unwrap();
+    let tensor_b = Tensor::from_shape(&[1000, 3, 3], img).unwrap();
     println!("Shape (B): {:?}", tensor_b.shape());
-    drop(img);
 
     c.bench_function(
-        "tensor::mul: (1000x1000x3) * (1000x3x3) = (1000x1000x3)",
+        "tensor::matmul: (1000x1000x3) * (1000x3x3) = (1000x1000x3)",
         |b| {
             b.iter(|| {
-                tensor_a.mul(&tensor_b.view()).unwrap();
+                tensor_a.matmul(&tensor_b.view()).unwrap();
             })
         },
     );
@@ -70,21 +67,20 @@ fn bench_mul(c: &mut Criterion) {
     println!("##### Bench: (10000x10000x3) * (10000x3x3) = (10000x10000x3) #####");
     let img =
         img_to_vec("/usr/local/dev/rs_math/rs_math/benches/input/matrix_imgs/10000x10000x3.png");
-    let tensor_a = Tensor::from_shape(&[10000, 10000, 3], &img).unwrap();
+    let tensor_a = Tensor::from_shape(&[10000, 10000, 3], img).unwrap();
     println!("Shape (A): {:?}", tensor_a.shape());
 
     let img = img_to_vec("/usr/local/dev/rs_math/rs_math/benches/input/matrix_imgs/10000x3x3.png");
-    let tensor_b = Tensor::from_shape(&[10000, 3, 3], &img).unwrap();
+    let tensor_b = Tensor::from_shape(&[10000, 3, 3], img).unwrap();
     println!("Shape (B): {:?}", tensor_b.shape());
-    drop(img);
 
     let mut group = c.benchmark_group("sample_size:10");
     group.sample_size(10);
     group.bench_function(
-        "tensor::mul: (10000x10000x3) * (10000x3x3) = (10000x10000x3)",
+        "tensor::matmul: (10000x10000x3) * (10000x3x3) = (10000x10000x3)",
         |b| {
             b.iter(|| {
-                tensor_a.mul(&tensor_b.view()).unwrap();
+                tensor_a.matmul(&tensor_b.view()).unwrap();
             })
         },
     );
@@ -1,5 +1,7 @@
 use super::*;
-use crate::tensor::{Error, Tensor, TensorEq, TensorTypeNumeric, TensorView, TensorViewMut};
+use crate::tensor::{
+    Error, Tensor, TensorEq, TensorTypeComplex, TensorTypeNumeric, TensorView, TensorViewMut,
+};
 use std::fmt::Debug;
 
 impl<'a, U> Matrix<'a, U>
@@ -11,6 +13,29 @@ where
         Ok(Matrix { tensor: matrix })
     }
 
+    pub fn ones(rows: usize, cols: usize) -> Result<Matrix<'a, U>, Error> {
+        let matrix = Tensor::ones(&[rows, cols])?;
+        Ok(Matrix { tensor: matrix })
+    }
+
+    pub fn full(rows: usize, cols: usize, value: U) -> Result<Matrix<'a, U>, Error> {
+        let matrix = Tensor::full(&[rows, cols], value)?;
+        Ok(Matrix { tensor: matrix })
+    }
+
+    pub fn eye(rows: usize, cols: usize) -> Result<Matrix<'a, U>, Error> {
+        let matrix = Tensor::eye(rows, cols)?;
+        Ok(Matrix { tensor: matrix })
+    }
+
+    pub fn from_fn<F>(rows: usize, cols: usize, mut f: F) -> Result<Matrix<'a, U>, Error>
+    where
+        F: FnMut(usize, usize) -> U,
+    {
+        let matrix = Tensor::from_fn(&[rows, cols], |index| f(index[0], index[1]))?;
+        Ok(Matrix { tensor: matrix })
+    }
+
     pub fn from_vec(data: Vec<Vec<U>>) -> Result<Matrix<'a, U>, Error> {
         let matrix = Tensor::from_vec(data)?;
         Ok(Matrix { tensor: matrix })
@@ -24,7 +49,7 @@ where
         Ok(Matrix { tensor: matrix })
     }
 
-    pub fn from_shape(shape: &[usize], data: &Vec<U>) -> Result<Matrix<'static, U>, Error> {
+    pub fn from_shape(shape: &[usize], data: Vec<U>) -> Result<Matrix<'static, U>, Error> {
         let matrix = Tensor::from_shape(shape, data)?;
         Ok(Matrix { tensor: matrix })
     }
@@ -70,7 +95,7 @@ where
     }
 
     pub fn mul(&self, matrix_b: &Matrix<'a, U>) -> Result<Matrix<'static, U>, Error> {
-        let matrix_c = self.tensor.mul(&matrix_b.tensor.view())?;
+        let matrix_c = self.tensor.matmul(&matrix_b.tensor.view())?;
 
         Ok(Matrix { tensor: matrix_c })
     }
@@ -103,6 +128,19 @@ where
     }
 }
 
+impl<'a, C> Matrix<'a, C>
+where
+    C: TensorTypeComplex,
+{
+    /// Conjugate transpose (Hermitian adjoint).
+    #[inline(always)]
+    pub fn conj_t(&self) -> Matrix<'static, C> {
+        Matrix {
+            tensor: self.tensor.conj_t(),
+        }
+    }
+}
+
 impl<'a, U, T> PartialEq<Vec<T>> for Matrix<'a, U>
 where
     U: TensorTypeNumeric + PartialEq + Debug,
@@ -1,5 +1,6 @@
 use super::*;
-use crate::tensor::{Error, Tensor};
+use crate::tensor::{Complex, Error, QrMode, Tensor, TensorTypeFloat, TensorTypeNumeric, Triangle};
+use crate::vector::Vector;
 use std::cmp::Eq;
 use std::hash::Hash;
 
@@ -9,3 +10,191 @@ impl<'a> Matrix<'a, u8> {
         Ok(Matrix { tensor })
     }
 }
+
+impl<'a, F> Matrix<'a, F>
+where
+    F: TensorTypeFloat + 'static,
+{
+    /// LU decomposition with partial pivoting, `A = P * L * U`.
+    #[allow(clippy::type_complexity)]
+    pub fn lu(
+        &self,
+    ) -> Result<(Matrix<'static, F>, Matrix<'static, F>, Matrix<'static, F>), Error> {
+        let (p, l, u) = self.tensor.lu()?;
+        Ok((
+            Matrix { tensor: p },
+            Matrix { tensor: l },
+            Matrix { tensor: u },
+        ))
+    }
+
+    /// Solves `A * X = B` for the `n x k` right-hand sides in `matrix_b`.
+    pub fn solve(&self, matrix_b: &Matrix<'_, F>) -> Result<Matrix<'static, F>, Error> {
+        let tensor = self.tensor.solve(&matrix_b.tensor.view())?;
+        Ok(Matrix { tensor })
+    }
+
+    pub fn det(&self) -> Result<F, Error> {
+        self.tensor.det()?.getval(&[])
+    }
+
+    /// Sign and natural log of the absolute value of the determinant.
+    pub fn slogdet(&self) -> Result<(F, F), Error> {
+        let (sign, logabsdet) = self.tensor.slogdet()?;
+        Ok((sign.getval(&[])?, logabsdet.getval(&[])?))
+    }
+
+    pub fn inv(&self) -> Result<Matrix<'static, F>, Error> {
+        let tensor = self.tensor.inv()?;
+        Ok(Matrix { tensor })
+    }
+
+    /// Householder QR decomposition, `A = Q * R`.
+    pub fn qr(&self, mode: QrMode) -> Result<(Matrix<'static, F>, Matrix<'static, F>), Error> {
+        let (q, r) = self.tensor.qr(mode)?;
+        Ok((Matrix { tensor: q }, Matrix { tensor: r }))
+    }
+
+    /// Cholesky factor `L` of a symmetric positive definite matrix,
+    /// `A = L * L^T`.
+    pub fn cholesky(&self) -> Result<Matrix<'static, F>, Error> {
+        let tensor = self.tensor.cholesky()?;
+        Ok(Matrix { tensor })
+    }
+
+    /// Least-squares solution of `A * X = B` for the `m x k` right-hand sides
+    /// in `matrix_b`.
+    pub fn lstsq(&self, matrix_b: &Matrix<'_, F>) -> Result<Matrix<'static, F>, Error> {
+        let tensor = self.tensor.lstsq(&matrix_b.tensor.view())?;
+        Ok(Matrix { tensor })
+    }
+
+    /// Solves `A * X = B`, or `A^T * X = B` with `trans`, for a triangular
+    /// `A`. Only the triangle selected by `uplo` is read.
+    pub fn solve_triangular(
+        &self,
+        matrix_b: &Matrix<'_, F>,
+        uplo: Triangle,
+        trans: bool,
+        unit_diagonal: bool,
+    ) -> Result<Matrix<'static, F>, Error> {
+        let tensor =
+            self.tensor
+                .solve_triangular(&matrix_b.tensor.view(), uplo, trans, unit_diagonal)?;
+        Ok(Matrix { tensor })
+    }
+
+    /// Solves `A * X = B` for a banded `A` with `l` sub- and `u`
+    /// superdiagonals, where `self` holds `A` in `(l + u + 1) x n` LAPACK band
+    /// storage.
+    pub fn solve_banded(
+        &self,
+        l: usize,
+        u: usize,
+        matrix_b: &Matrix<'_, F>,
+    ) -> Result<Matrix<'static, F>, Error> {
+        let tensor = self.tensor.solve_banded(l, u, &matrix_b.tensor.view())?;
+        Ok(Matrix { tensor })
+    }
+
+    /// Solves `A * X = B` for a tridiagonal `A` with the subdiagonal `dl`,
+    /// diagonal `d` and superdiagonal `du`.
+    pub fn solve_tridiagonal(
+        dl: &[F],
+        d: &[F],
+        du: &[F],
+        matrix_b: &Matrix<'_, F>,
+    ) -> Result<Matrix<'static, F>, Error> {
+        let [dl, d, du] = [dl, d, du].map(|diag| Tensor::from_shape(&[diag.len()], diag.to_vec()));
+        let tensor = Tensor::solve_tridiagonal(
+            &dl?.view(),
+            &d?.view(),
+            &du?.view(),
+            &matrix_b.tensor.view(),
+        )?;
+        Ok(Matrix { tensor })
+    }
+
+    pub fn expm(&self) -> Result<Matrix<'static, F>, Error> {
+        let tensor = self.tensor.expm()?;
+        Ok(Matrix { tensor })
+    }
+
+    /// Principal matrix logarithm.
+    pub fn logm(&self) -> Result<Matrix<'static, F>, Error> {
+        let tensor = self.tensor.logm()?;
+        Ok(Matrix { tensor })
+    }
+
+    /// Principal matrix square root.
+    pub fn sqrtm(&self) -> Result<Matrix<'static, F>, Error> {
+        let tensor = self.tensor.sqrtm()?;
+        Ok(Matrix { tensor })
+    }
+
+    pub fn matrix_power(&self, p: i32) -> Result<Matrix<'static, F>, Error> {
+        let tensor = self.tensor.matrix_power(p)?;
+        Ok(Matrix { tensor })
+    }
+
+    /// Eigenvalues in ascending order and eigenvectors (as columns) of a real
+    /// symmetric matrix. Only the lower triangle is read.
+    pub fn eigh(&self) -> Result<(Vector<'static, F>, Matrix<'static, F>), Error> {
+        let (w, v) = self.tensor.eigh()?;
+        Ok((Vector::from_vec(w.data())?, Matrix { tensor: v }))
+    }
+
+    /// Singular value decomposition, `A = U * diag(S) * Vt`, with the
+    /// singular values in descending order.
+    #[allow(clippy::type_complexity)]
+    pub fn svd(
+        &self,
+        full_matrices: bool,
+    ) -> Result<(Matrix<'static, F>, Vector<'static, F>, Matrix<'static, F>), Error> {
+        let (u, s, vt) = self.tensor.svd(full_matrices)?;
+        Ok((
+            Matrix { tensor: u },
+            Vector::from_vec(s.data())?,
+            Matrix { tensor: vt },
+        ))
+    }
+
+    /// Moore-Penrose pseudo-inverse.
+    pub fn pinv(&self) -> Result<Matrix<'static, F>, Error> {
+        let tensor = self.tensor.pinv()?;
+        Ok(Matrix { tensor })
+    }
+
+    pub fn matrix_rank(&self) -> Result<usize, Error> {
+        self.tensor.matrix_rank()?.getval(&[])
+    }
+
+    /// Condition number in the 2-norm.
+    pub fn cond(&self) -> Result<F, Error> {
+        self.tensor.cond()?.getval(&[])
+    }
+
+    /// Nuclear norm, the sum of the singular values.
+    pub fn norm_nuc(&self) -> Result<F, Error> {
+        self.tensor.norm_nuc()?.getval(&[])
+    }
+
+    /// Spectral norm, the largest singular value.
+    pub fn norm_spectral(&self) -> Result<F, Error> {
+        self.tensor.norm_spectral()?.getval(&[])
+    }
+}
+
+impl<'a, F> Matrix<'a, F>
+where
+    F: TensorTypeFloat + 'static,
+    Complex<F>: TensorTypeNumeric,
+{
+    /// Complex eigenvalues and eigenvectors (as columns) of a general square
+    /// matrix.
+    #[allow(clippy::type_complexity)]
+    pub fn eig(&self) -> Result<(Vector<'static, Complex<F>>, Matrix<'static, Complex<F>>), Error> {
+        let (w, v) = self.tensor.eig()?;
+        Ok((Vector::from_vec(w.data())?, Matrix { tensor: v }))
+    }
+}
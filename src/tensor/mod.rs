@@ -1,17 +1,22 @@
-use num_traits::Bounded;
+use num_traits::{Bounded, NumCast, One};
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub};
+use num_traits::{SaturatingAdd, SaturatingMul, SaturatingSub};
+use num_traits::{WrappingAdd, WrappingMul, WrappingSub};
+use std::borrow::Cow;
 use std::fmt::{Debug, Display};
 use std::marker::PhantomData;
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 use std::ops::{Deref, DerefMut};
 
+/// Any element type a tensor can hold. Shape manipulation, indexing,
+/// iteration and comparison only need this, so they also work on boolean
+/// masks.
+pub trait TensorType: Default + Copy + Debug + Display + PartialEq {}
+
 pub trait TensorTypeNumeric:
-    Default
-    + Copy
-    + Debug
-    + Display
-    + PartialEq
-    + PartialOrd
-    + Bounded
+    TensorType
+    + One
+    + NumCast
     + AddAssign
     + SubAssign
     + MulAssign
@@ -21,21 +26,168 @@ pub trait TensorTypeNumeric:
     + Mul<Output = Self>
     + Div<Output = Self>
 {
+    /// Division that returns `None` instead of panicking (integer division by
+    /// zero or overflow). Floating point types always succeed.
+    fn try_div(self, rhs: Self) -> Option<Self>;
+}
+
+/// Element types with an ordering and bounds, i.e. all real types. Comparisons
+/// such as `max`, `min`, `argmax` or `relu` are only available for these.
+pub trait TensorTypeOrdered: TensorTypeNumeric + PartialOrd + Bounded {}
+
+/// Integer element types, which get checked, saturating and wrapping
+/// arithmetic.
+pub trait TensorTypeInt:
+    TensorTypeOrdered
+    + CheckedAdd
+    + CheckedSub
+    + CheckedMul
+    + SaturatingAdd
+    + SaturatingSub
+    + SaturatingMul
+    + WrappingAdd
+    + WrappingSub
+    + WrappingMul
+{
+}
+
+macro_rules! impl_tensor_type_int {
+    ($($t:ty),*) => {
+        $(
+            impl TensorType for $t {}
+
+            impl TensorTypeNumeric for $t {
+                #[inline(always)]
+                fn try_div(self, rhs: Self) -> Option<Self> {
+                    self.checked_div(rhs)
+                }
+            }
+
+            impl TensorTypeOrdered for $t {}
+            impl TensorTypeInt for $t {}
+        )*
+    };
+}
+
+macro_rules! impl_tensor_type_float {
+    ($($t:ty),*) => {
+        $(
+            impl TensorType for $t {}
+
+            impl TensorTypeNumeric for $t {
+                #[inline(always)]
+                fn try_div(self, rhs: Self) -> Option<Self> {
+                    Some(self / rhs)
+                }
+            }
+
+            impl TensorTypeOrdered for $t {}
+            impl TensorTypeFloat for $t {}
+
+            impl TensorTypeAbs for $t {
+                type Real = $t;
+
+                #[inline(always)]
+                fn magnitude(self) -> $t {
+                    num_traits::Float::abs(self)
+                }
+            }
+        )*
+    };
 }
 
-impl TensorTypeNumeric for i8 {}
-impl TensorTypeNumeric for u8 {}
-impl TensorTypeNumeric for i32 {}
-impl TensorTypeNumeric for u32 {}
-impl TensorTypeNumeric for i64 {}
-impl TensorTypeNumeric for u64 {}
-impl TensorTypeNumeric for f32 {}
-impl TensorTypeNumeric for f64 {}
+pub trait TensorTypeFloat: TensorTypeOrdered + num_traits::Float {}
+
+/// Element types with an absolute value. Complex types map to their real
+/// component type.
+pub trait TensorTypeAbs: TensorTypeNumeric {
+    type Real: TensorTypeNumeric;
+
+    fn magnitude(self) -> Self::Real;
+}
+
+impl TensorType for bool {}
+
+impl_tensor_type_int!(i8, u8, i16, u16, i32, u32, i64, u64, i128, isize, usize);
+impl_tensor_type_float!(f32, f64);
+
+#[cfg(feature = "half")]
+impl_tensor_type_float!(half::f16, half::bf16);
+
+pub trait TensorTypeComplex: TensorTypeNumeric {
+    type Real: TensorTypeFloat;
+
+    fn re(self) -> Self::Real;
+    fn im(self) -> Self::Real;
+    fn conj(self) -> Self;
+    /// Magnitude `sqrt(re^2 + im^2)`.
+    fn abs(self) -> Self::Real;
+    /// Phase angle in radians, in `(-pi, pi]`.
+    fn arg(self) -> Self::Real;
+    /// Reinterprets the elements as their interleaved real and imaginary parts.
+    fn as_parts(data: &[Self]) -> &[Self::Real];
+}
+
+macro_rules! impl_tensor_type_complex {
+    ($($t:ty),*) => {
+        $(
+            impl TensorType for Complex<$t> {}
+
+            impl TensorTypeNumeric for Complex<$t> {
+                #[inline(always)]
+                fn try_div(self, rhs: Self) -> Option<Self> {
+                    Some(self / rhs)
+                }
+            }
+
+            impl TensorTypeAbs for Complex<$t> {
+                type Real = $t;
+
+                #[inline(always)]
+                fn magnitude(self) -> $t {
+                    self.norm()
+                }
+            }
+
+            impl TensorTypeComplex for Complex<$t> {
+                type Real = $t;
+
+                #[inline(always)]
+                fn re(self) -> $t {
+                    self.re
+                }
 
-pub trait TensorTypeFloat: TensorTypeNumeric + num_traits::Float {}
+                #[inline(always)]
+                fn im(self) -> $t {
+                    self.im
+                }
 
-impl TensorTypeFloat for f32 {}
-impl TensorTypeFloat for f64 {}
+                #[inline(always)]
+                fn conj(self) -> Self {
+                    Complex::conj(&self)
+                }
+
+                #[inline(always)]
+                fn abs(self) -> $t {
+                    self.norm()
+                }
+
+                #[inline(always)]
+                fn arg(self) -> $t {
+                    Complex::arg(self)
+                }
+
+                fn as_parts(data: &[Self]) -> &[$t] {
+                    // SAFETY: `Complex<T>` is `#[repr(C)]` with the fields
+                    // `re` and `im`, so it has the layout of `[T; 2]`.
+                    unsafe { std::slice::from_raw_parts(data.as_ptr() as *const $t, data.len() * 2) }
+                }
+            }
+        )*
+    };
+}
+
+impl_tensor_type_complex!(f32, f64);
 
 #[derive(Debug)]
 pub struct TensorBase<'a, U, S> {
@@ -50,6 +202,7 @@ pub struct TensorBase<'a, U, S> {
 pub type Tensor<'a, U> = TensorBase<'a, U, Vec<U>>;
 pub type TensorView<'a, U> = TensorBase<'a, U, &'a [U]>;
 pub type TensorViewMut<'a, U> = TensorBase<'a, U, &'a mut [U]>;
+pub type TensorCow<'a, U> = TensorBase<'a, U, Cow<'a, [U]>>;
 
 #[derive(Debug)]
 pub enum Error {
@@ -80,6 +233,34 @@ pub enum Error {
     InvalidParam {
         err_msg: String,
     },
+    DivisionByZero {
+        index: Vec<usize>,
+    },
+    Overflow {
+        index: Vec<usize>,
+    },
+    SingularMatrix {
+        index: Vec<usize>,
+    },
+    NotPositiveDefinite {
+        index: Vec<usize>,
+    },
+    NoConvergence {
+        index: Vec<usize>,
+    },
+    RaggedData {
+        index: Vec<usize>,
+        len: usize,
+        expected: usize,
+    },
+    DataLengthMismatch {
+        shape: Vec<usize>,
+        len: usize,
+    },
+    InvalidCast {
+        index: Vec<usize>,
+        value: String,
+    },
     InvalidFileContents {
         err_msg: String,
     },
@@ -88,11 +269,67 @@ pub enum Error {
     },
 }
 
-pub trait TensorStorage<U: TensorTypeNumeric>: AsRef<[U]> + Deref<Target = [U]> {
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Norm {
+    L1,
+    L2,
+    Linf,
+    Fro,
+}
+
+/// How `cast_with` handles values that can't be represented in the target
+/// element type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CastPolicy {
+    /// Fail with `Error::InvalidCast`.
+    Checked,
+    /// Clamp to the nearest bound of the target type, NaN becomes zero.
+    Saturating,
+    /// Same as an `as` conversion: integers wrap around, floats saturate.
+    Wrapping,
+}
+
+/// Shape of the factors returned by `qr` for an `m x n` matrix, with
+/// `k = min(m, n)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QrMode {
+    /// `Q` is `m x k` and `R` is `k x n`.
+    Reduced,
+    /// `Q` is `m x m` and `R` is `m x n`.
+    Complete,
+}
+
+/// Which triangle of a matrix holds its elements.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Triangle {
+    Upper,
+    Lower,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SliceElem {
+    Index(isize),
+    Range {
+        start: isize,
+        end: Option<isize>,
+        step: usize,
+    },
+    NewAxis,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NewAxis;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SliceInfo {
+    elems: Vec<SliceElem>,
+}
+
+pub trait TensorStorage<U: TensorType>: AsRef<[U]> + Deref<Target = [U]> {
     fn get(&self, index: usize) -> U;
 }
 
-pub trait TensorStorageMut<U: TensorTypeNumeric>: AsMut<[U]> + DerefMut<Target = [U]> {
+pub trait TensorStorageMut<U: TensorType>: AsMut<[U]> + DerefMut<Target = [U]> {
     fn get(&self, index: usize) -> U;
     fn set(&mut self, index: usize, value: U);
 }
@@ -120,8 +357,22 @@ pub struct TensorIterMut<'a, U> {
 }
 
 pub mod tensor_arithmetic;
+pub mod tensor_cast;
+pub mod tensor_compare;
+pub mod tensor_complex;
 pub mod tensor_core;
+pub mod tensor_eigen;
+pub mod tensor_einsum;
 pub mod tensor_eq;
 pub mod tensor_error;
+pub mod tensor_int;
 pub mod tensor_iter;
+pub mod tensor_join;
 pub mod tensor_linalg;
+pub mod tensor_matfun;
+pub mod tensor_math;
+pub mod tensor_reduce;
+pub mod tensor_slice;
+
+pub use num_complex::Complex;
+pub use tensor_arithmetic::broadcast_shapes;
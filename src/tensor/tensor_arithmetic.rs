@@ -1,12 +1,29 @@
 use super::*;
-use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use std::slice;
+
+/// 0-d view of a scalar, which broadcasts against any tensor.
+#[inline(always)]
+pub(crate) fn scalar_view<U: TensorType>(scalar: &U) -> TensorView<'_, U> {
+    TensorView {
+        shape: vec![],
+        strides: vec![],
+        offset: 0,
+        data: slice::from_ref(scalar),
+        _u: PhantomData,
+        _s: PhantomData,
+    }
+}
 
 impl<'a, U, S> TensorBase<'a, U, S>
 where
-    U: TensorTypeNumeric,
+    U: TensorType,
     S: TensorStorage<U>,
 {
-    fn shape_indexes(&self, shape: &'a [usize]) -> impl Iterator<Item = Vec<usize>> + 'a {
+    pub(crate) fn shape_indexes(
+        &self,
+        shape: &'a [usize],
+    ) -> impl Iterator<Item = Vec<usize>> + 'a {
         let ndim = shape.len();
         let total = shape.iter().product();
 
@@ -64,6 +81,13 @@ where
         };
 
         if batch_mul {
+            if ndim_a < 2 || ndim_b < 2 {
+                return Err(Error::ShapeMismatchBroadcast {
+                    shape_a: shape_a.to_vec(),
+                    shape_b: shape_b.to_vec(),
+                });
+            }
+
             let (batch_a, m, k_a) = match ndim_a {
                 2 => (vec![], shape_a[0], shape_a[1]),
                 _ => (
@@ -99,6 +123,45 @@ where
         Ok(shape_c)
     }
 
+    /// Returns a read-only view of the tensor broadcast to `shape`. Broadcast
+    /// axes get a zero stride, so no data is copied.
+    #[inline(always)]
+    pub fn broadcast_to(&'a self, shape: &[usize]) -> Result<TensorView<'a, U>, Error> {
+        self.broadcast_view(shape)
+    }
+
+    pub(crate) fn broadcast_view(&self, shape: &[usize]) -> Result<TensorView<'_, U>, Error> {
+        let err = || Error::ShapeMismatchBroadcast {
+            shape_a: self.shape(),
+            shape_b: shape.to_vec(),
+        };
+
+        if shape.len() < self.ndim() {
+            return Err(err());
+        }
+
+        // The tensor is aligned to the trailing dimensions
+        let skip = shape.len() - self.ndim();
+        let mut strides = vec![0; shape.len()];
+
+        for dim in 0..self.ndim() {
+            match (self.shape[dim], shape[skip + dim]) {
+                (size_a, size_c) if size_a == size_c => strides[skip + dim] = self.strides[dim],
+                (1, _) => strides[skip + dim] = 0,
+                _ => return Err(err()),
+            }
+        }
+
+        Ok(TensorView {
+            shape: shape.to_vec(),
+            strides,
+            offset: self.offset,
+            data: self.data.as_ref(),
+            _u: PhantomData,
+            _s: PhantomData,
+        })
+    }
+
     #[inline(always)]
     fn offset(index: &[usize], shape: &[usize], strides: &[usize]) -> usize {
         index
@@ -111,21 +174,30 @@ where
             .sum()
     }
 
-    #[inline(always)]
-    pub fn add_alias(&self, tensor_b: &TensorView<'_, U>) -> Result<Tensor<'static, U>, Error> {
-        self.add(tensor_b)
-    }
-
-    pub fn add(&self, tensor_b: &TensorView<'_, U>) -> Result<Tensor<'static, U>, Error> {
+    pub(crate) fn zip_bc<W, V, F>(
+        &self,
+        tensor_b: &TensorView<'_, W>,
+        mut op: F,
+    ) -> Result<Tensor<'static, V>, Error>
+    where
+        W: TensorType,
+        V: Default + Copy,
+        F: FnMut(&[usize], U, W) -> Result<V, Error>,
+    {
         let shape_c = Self::shape_bc(&self.shape, &tensor_b.shape, false)?;
         let strides_c = self.compute_strides(&shape_c);
-        let mut data_c = vec![U::default(); shape_c.iter().product()];
+        let mut data_c = vec![V::default(); shape_c.iter().product()];
+
+        // Operands with fewer dimensions are aligned to the trailing dimensions
+        let skip_a = shape_c.len() - self.ndim();
+        let skip_b = shape_c.len() - tensor_b.ndim();
 
         for index in self.shape_indexes(&shape_c) {
-            let offset_a = Self::offset(&index, &self.shape, &self.strides);
-            let offset_b = Self::offset(&index, &tensor_b.shape, &tensor_b.strides);
+            let offset_a = self.offset + Self::offset(&index[skip_a..], &self.shape, &self.strides);
+            let offset_b = tensor_b.offset
+                + Self::offset(&index[skip_b..], &tensor_b.shape, &tensor_b.strides);
             let offset_c = Self::offset(&index, &shape_c, &strides_c);
-            data_c[offset_c] = self.data[offset_a] + tensor_b.data[offset_b];
+            data_c[offset_c] = op(&index, self.data[offset_a], tensor_b.data[offset_b])?;
         }
 
         Ok(Tensor {
@@ -137,6 +209,21 @@ where
             _s: PhantomData,
         })
     }
+}
+
+impl<'a, U, S> TensorBase<'a, U, S>
+where
+    U: TensorTypeNumeric,
+    S: TensorStorage<U>,
+{
+    #[inline(always)]
+    pub fn add_alias(&self, tensor_b: &TensorView<'_, U>) -> Result<Tensor<'static, U>, Error> {
+        self.add(tensor_b)
+    }
+
+    pub fn add(&self, tensor_b: &TensorView<'_, U>) -> Result<Tensor<'static, U>, Error> {
+        self.zip_bc(tensor_b, |_, a, b| Ok(a + b))
+    }
 
     #[inline(always)]
     pub fn sub_alias(&self, tensor_b: &TensorView<'_, U>) -> Result<Tensor<'static, U>, Error> {
@@ -144,33 +231,37 @@ where
     }
 
     pub fn sub(&self, tensor_b: &TensorView<'_, U>) -> Result<Tensor<'static, U>, Error> {
-        let shape_c = Self::shape_bc(&self.shape, &tensor_b.shape, false)?;
-        let strides_c = self.compute_strides(&shape_c);
-        let mut data_c = vec![U::default(); shape_c.iter().product()];
-
-        for index in self.shape_indexes(&shape_c) {
-            let offset_a = Self::offset(&index, &self.shape, &self.strides);
-            let offset_b = Self::offset(&index, &tensor_b.shape, &tensor_b.strides);
-            let offset_c = Self::offset(&index, &shape_c, &strides_c);
-            data_c[offset_c] = self.data[offset_a] - tensor_b.data[offset_b];
-        }
+        self.zip_bc(tensor_b, |_, a, b| Ok(a - b))
+    }
 
-        Ok(Tensor {
-            data: data_c,
-            shape: shape_c,
-            strides: strides_c,
-            offset: 0,
-            _u: PhantomData,
-            _s: PhantomData,
-        })
+    pub fn mul_elem(&self, tensor_b: &TensorView<'_, U>) -> Result<Tensor<'static, U>, Error> {
+        self.zip_bc(tensor_b, |_, a, b| Ok(a * b))
     }
 
     #[inline(always)]
-    pub fn mul_alias(&self, tensor_b: &TensorView<'_, U>) -> Result<Tensor<'static, U>, Error> {
-        self.mul(tensor_b)
+    pub fn div_alias(&self, tensor_b: &TensorView<'_, U>) -> Result<Tensor<'static, U>, Error> {
+        self.div(tensor_b)
     }
 
-    pub fn mul(&self, tensor_b: &TensorView<U>) -> Result<Tensor<'static, U>, Error> {
+    /// Element-wise division with broadcasting. Integer division by zero is
+    /// reported as `Error::DivisionByZero` and overflow (`MIN / -1`) as
+    /// `Error::Overflow`, both with the index of the offending element in the
+    /// broadcast result.
+    pub fn div(&self, tensor_b: &TensorView<'_, U>) -> Result<Tensor<'static, U>, Error> {
+        self.zip_bc(tensor_b, |index, a, b| {
+            a.try_div(b).ok_or_else(|| {
+                let index = index.to_vec();
+                match b == U::default() {
+                    true => Error::DivisionByZero { index },
+                    false => Error::Overflow { index },
+                }
+            })
+        })
+    }
+
+    /// Batched matrix product of tensors with at least 2 dimensions,
+    /// broadcasting the leading dimensions. Backs `matmul` and `bmm`.
+    fn matmul_batched(&self, tensor_b: &TensorView<U>) -> Result<Tensor<'static, U>, Error> {
         let shape_a = self.shape();
         let shape_b = tensor_b.shape();
         // let shape_c = self.bc_shape(&tensor_b, true)?;
@@ -190,9 +281,23 @@ where
 
         let mut data_c = vec![U::default(); shape_c.iter().product()];
 
+        // Batch dimensions are aligned to the trailing dimensions
+        let skip_a = ndim_c - ndim_a;
+        let skip_b = ndim_c - ndim_b;
+
         for index in self.shape_indexes(&shape_c[..ndim_c - 2]) {
-            let base_a = Self::offset(&index, &shape_a[..ndim_a - 2], &strides_a[..ndim_a - 2]);
-            let base_b = Self::offset(&index, &shape_b[..ndim_b - 2], &strides_b[..ndim_b - 2]);
+            let base_a = self.offset
+                + Self::offset(
+                    &index[skip_a..],
+                    &shape_a[..ndim_a - 2],
+                    &strides_a[..ndim_a - 2],
+                );
+            let base_b = tensor_b.offset
+                + Self::offset(
+                    &index[skip_b..],
+                    &shape_b[..ndim_b - 2],
+                    &strides_b[..ndim_b - 2],
+                );
             let base_c = Self::offset(&index, &shape_c[..ndim_c - 2], &strides_c[..ndim_c - 2]);
 
             // MxN - NxK
@@ -222,6 +327,108 @@ where
             _s: PhantomData,
         })
     }
+
+    fn matmul_view(&self, shape: Vec<usize>, strides: Vec<usize>) -> TensorView<'_, U> {
+        TensorView {
+            shape,
+            strides,
+            offset: self.offset,
+            data: self.data.as_ref(),
+            _u: PhantomData,
+            _s: PhantomData,
+        }
+    }
+
+    /// Matrix product following the semantics of NumPy's `matmul` (`@`):
+    /// - N-D x N-D (N >= 2): Batched matrix multiplication with broadcasting
+    ///   over the leading (batch) dimensions.
+    /// - 1-D x N-D: The vector is treated as a row vector `[1, k]` and the
+    ///   prepended dimension is removed from the result.
+    /// - N-D x 1-D: The vector is treated as a column vector `[k, 1]` and the
+    ///   appended dimension is removed from the result.
+    /// - 1-D x 1-D: Dot product, returned as a 0-d tensor.
+    pub fn matmul(&self, tensor_b: &TensorView<'_, U>) -> Result<Tensor<'static, U>, Error> {
+        let ndim_a = self.ndim();
+        let ndim_b = tensor_b.ndim();
+
+        if ndim_a == 0 || ndim_b == 0 {
+            return Err(Error::InvalidParam {
+                err_msg: "matmul: 0-d tensors are not supported, use mul_scalar".to_string(),
+            });
+        }
+
+        let view_a = match ndim_a {
+            1 => self.matmul_view(vec![1, self.shape[0]], vec![0, self.strides[0]]),
+            _ => self.matmul_view(self.shape(), self.strides()),
+        };
+
+        let view_b = match ndim_b {
+            1 => tensor_b.matmul_view(vec![tensor_b.shape[0], 1], vec![tensor_b.strides[0], 0]),
+            _ => tensor_b.matmul_view(tensor_b.shape(), tensor_b.strides()),
+        };
+
+        let mut tensor_c =
+            view_a
+                .matmul_batched(&view_b)
+                .map_err(|_| Error::ShapeMismatchBroadcast {
+                    shape_a: self.shape(),
+                    shape_b: tensor_b.shape(),
+                })?;
+
+        // Remove the dimensions added for the vector operands
+        let ndim_c = tensor_c.ndim();
+        if ndim_a == 1 {
+            tensor_c.shape.remove(ndim_c - 2);
+        }
+        if ndim_b == 1 {
+            tensor_c.shape.pop();
+        }
+        tensor_c.strides = tensor_c.compute_strides(&tensor_c.shape);
+
+        Ok(tensor_c)
+    }
+
+    /// Batched matrix multiplication of two 3-D tensors with the same batch
+    /// size: `[b, m, k] x [b, k, n] = [b, m, n]`. Unlike `matmul`, no
+    /// broadcasting is performed.
+    pub fn bmm(&self, tensor_b: &TensorView<'_, U>) -> Result<Tensor<'static, U>, Error> {
+        for ndim in [self.ndim(), tensor_b.ndim()] {
+            if ndim != 3 {
+                return Err(Error::DimensionMismatch {
+                    tensor_dim: ndim,
+                    dim: 3,
+                });
+            }
+        }
+
+        if self.shape[0] != tensor_b.shape[0] {
+            return Err(Error::ShapeMismatch {
+                shape_a: self.shape(),
+                shape_b: tensor_b.shape(),
+            });
+        }
+
+        self.matmul_batched(tensor_b)
+    }
+
+    /// Matrix-vector product: `[m, k] x [k] = [m]`.
+    pub fn mv(&self, tensor_b: &TensorView<'_, U>) -> Result<Tensor<'static, U>, Error> {
+        if self.ndim() != 2 {
+            return Err(Error::DimensionMismatch {
+                tensor_dim: self.ndim(),
+                dim: 2,
+            });
+        }
+
+        if tensor_b.ndim() != 1 {
+            return Err(Error::DimensionMismatch {
+                tensor_dim: tensor_b.ndim(),
+                dim: 1,
+            });
+        }
+
+        self.matmul(tensor_b)
+    }
 }
 
 impl<'a, U, S> TensorBase<'a, U, S>
@@ -230,95 +437,253 @@ where
     S: TensorStorage<U> + TensorStorageMut<U>,
 {
     pub fn add_scalar(&mut self, scaler: U) {
-        for idx in 0..self.nelems() {
-            self.data[idx] = self.data[idx] + scaler;
+        for val in self.iter_mut() {
+            *val += scaler;
         }
     }
 
     pub fn sub_scalar(&mut self, scaler: U) {
-        for idx in 0..self.nelems() {
-            self.data[idx] = self.data[idx] - scaler;
+        for val in self.iter_mut() {
+            *val -= scaler;
         }
     }
 
     pub fn mul_scalar(&mut self, scaler: U) {
-        for idx in 0..self.nelems() {
-            self.data[idx] = self.data[idx] * scaler;
+        for val in self.iter_mut() {
+            *val *= scaler;
         }
     }
-}
 
-impl<U, S1, S2> Add<&TensorBase<'_, U, S1>> for &TensorBase<'_, U, S2>
-where
-    U: TensorTypeNumeric + 'static,
-    S1: TensorStorage<U>,
-    S2: TensorStorage<U>,
-{
-    type Output = TensorBase<'static, U, Vec<U>>;
-
-    fn add(self, other: &TensorBase<'_, U, S1>) -> Self::Output {
-        // TODO: Use TensorView::from()
-        let tensor = self.add_alias(&other.view());
-        match tensor {
-            Ok(val) => return val,
-            Err(_) => Tensor {
-                shape: vec![],
-                strides: vec![],
-                offset: 0,
-                data: vec![],
-                _u: PhantomData,
-                _s: PhantomData,
-            },
+    /// In-place division by a scalar. Integer division by zero is reported as
+    /// `Error::DivisionByZero` and overflow as `Error::Overflow`, leaving the
+    /// tensor untouched.
+    pub fn div_scalar(&mut self, scaler: U) -> Result<(), Error>
+    where
+        U: 'static,
+    {
+        let tensor_c = self.div(&scalar_view(&scaler))?;
+        self.assign_bc(tensor_c)
+    }
+
+    fn assign_bc(&mut self, tensor_c: Tensor<'_, U>) -> Result<(), Error> {
+        if tensor_c.shape != self.shape {
+            return Err(Error::ShapeMismatchBroadcast {
+                shape_a: self.shape(),
+                shape_b: tensor_c.shape(),
+            });
+        }
+
+        for (val, res) in self.iter_mut().zip(tensor_c.data) {
+            *val = res;
         }
+
+        Ok(())
+    }
+
+    /// In-place element-wise multiplication. `tensor_b` must broadcast to the
+    /// shape of `self`; the tensor is left untouched on error.
+    pub fn mul_elem_inplace(&mut self, tensor_b: &TensorView<'_, U>) -> Result<(), Error>
+    where
+        U: 'static,
+    {
+        let tensor_c = self.mul_elem(tensor_b)?;
+        self.assign_bc(tensor_c)
+    }
+
+    /// In-place element-wise division. `tensor_b` must broadcast to the shape
+    /// of `self`; the tensor is left untouched on error.
+    pub fn div_inplace(&mut self, tensor_b: &TensorView<'_, U>) -> Result<(), Error>
+    where
+        U: 'static,
+    {
+        let tensor_c = self.div(tensor_b)?;
+        self.assign_bc(tensor_c)
     }
 }
 
-impl<U, S1, S2> Sub<&TensorBase<'_, U, S1>> for &TensorBase<'_, U, S2>
+/// Implements a fallible binary operator for every combination of borrowed
+/// and owned tensor operands. Errors (e.g. incompatible shapes) are returned
+/// to the caller instead of producing an empty tensor.
+macro_rules! impl_tensor_op {
+    ($op:ident, $fn:ident, $method:ident) => {
+        impl<U, S1, S2> $op<&TensorBase<'_, U, S1>> for &TensorBase<'_, U, S2>
+        where
+            U: TensorTypeNumeric + 'static,
+            S1: TensorStorage<U>,
+            S2: TensorStorage<U>,
+        {
+            type Output = Result<Tensor<'static, U>, Error>;
+
+            fn $fn(self, other: &TensorBase<'_, U, S1>) -> Self::Output {
+                // TODO: Use TensorView::from()
+                self.$method(&other.view())
+            }
+        }
+
+        impl<U, S> $op<&TensorBase<'_, U, S>> for Tensor<'_, U>
+        where
+            U: TensorTypeNumeric + 'static,
+            S: TensorStorage<U>,
+        {
+            type Output = Result<Tensor<'static, U>, Error>;
+
+            fn $fn(self, other: &TensorBase<'_, U, S>) -> Self::Output {
+                self.$method(&other.view())
+            }
+        }
+
+        impl<U, S> $op<Tensor<'_, U>> for &TensorBase<'_, U, S>
+        where
+            U: TensorTypeNumeric + 'static,
+            S: TensorStorage<U>,
+        {
+            type Output = Result<Tensor<'static, U>, Error>;
+
+            fn $fn(self, other: Tensor<'_, U>) -> Self::Output {
+                self.$method(&other.view())
+            }
+        }
+
+        impl<U> $op<Tensor<'_, U>> for Tensor<'_, U>
+        where
+            U: TensorTypeNumeric + 'static,
+        {
+            type Output = Result<Tensor<'static, U>, Error>;
+
+            fn $fn(self, other: Tensor<'_, U>) -> Self::Output {
+                self.$method(&other.view())
+            }
+        }
+    };
+}
+
+impl_tensor_op!(Add, add, add_alias);
+impl_tensor_op!(Sub, sub, sub_alias);
+impl_tensor_op!(Mul, mul, mul_elem);
+impl_tensor_op!(Div, div, div_alias);
+
+/// Implements a binary operator between an owned tensor and a scalar. The
+/// tensor's buffer is updated in place and returned.
+macro_rules! impl_tensor_scalar_op {
+    ($op:ident, $fn:ident, $method:ident) => {
+        impl<U> $op<U> for Tensor<'_, U>
+        where
+            U: TensorTypeNumeric + 'static,
+        {
+            type Output = Result<Tensor<'static, U>, Error>;
+
+            fn $fn(mut self, other: U) -> Self::Output {
+                self.$method(other);
+
+                Ok(Tensor {
+                    shape: self.shape,
+                    strides: self.strides,
+                    offset: self.offset,
+                    data: self.data,
+                    _u: PhantomData,
+                    _s: PhantomData,
+                })
+            }
+        }
+    };
+}
+
+impl_tensor_scalar_op!(Add, add, add_scalar);
+impl_tensor_scalar_op!(Sub, sub, sub_scalar);
+impl_tensor_scalar_op!(Mul, mul, mul_scalar);
+
+impl<U> Div<U> for Tensor<'_, U>
 where
     U: TensorTypeNumeric + 'static,
-    S1: TensorStorage<U>,
-    S2: TensorStorage<U>,
 {
-    type Output = TensorBase<'static, U, Vec<U>>;
-
-    fn sub(self, other: &TensorBase<'_, U, S1>) -> Self::Output {
-        // TODO: Use TensorView::from()
-        let tensor = self.sub_alias(&other.view());
-        match tensor {
-            Ok(val) => return val,
-            Err(_) => Tensor {
-                shape: vec![],
-                strides: vec![],
-                offset: 0,
-                data: vec![],
-                _u: PhantomData,
-                _s: PhantomData,
-            },
-        }
+    type Output = Result<Tensor<'static, U>, Error>;
+
+    fn div(mut self, other: U) -> Self::Output {
+        self.div_scalar(other)?;
+
+        Ok(Tensor {
+            shape: self.shape,
+            strides: self.strides,
+            offset: self.offset,
+            data: self.data,
+            _u: PhantomData,
+            _s: PhantomData,
+        })
     }
 }
 
-impl<U, S1, S2> Mul<&TensorBase<'_, U, S1>> for &TensorBase<'_, U, S2>
+/// Implements a binary operator between a borrowed tensor and a scalar. The
+/// result is a new tensor with the shape of the operand.
+macro_rules! impl_tensor_ref_scalar_op {
+    ($op:ident, $fn:ident, $method:ident) => {
+        impl<U, S> $op<U> for &TensorBase<'_, U, S>
+        where
+            U: TensorTypeNumeric + 'static,
+            S: TensorStorage<U>,
+        {
+            type Output = Result<Tensor<'static, U>, Error>;
+
+            fn $fn(self, other: U) -> Self::Output {
+                self.$method(&scalar_view(&other))
+            }
+        }
+    };
+}
+
+impl_tensor_ref_scalar_op!(Add, add, add);
+impl_tensor_ref_scalar_op!(Sub, sub, sub);
+impl_tensor_ref_scalar_op!(Mul, mul, mul_elem);
+impl_tensor_ref_scalar_op!(Div, div, div);
+
+/// Implements the reverse operators `scalar op tensor` for concrete element
+/// types, as `impl Sub<Tensor<U>> for U` isn't allowed for a generic `U`.
+macro_rules! impl_scalar_tensor_op {
+    ($($t:ty),*) => {
+        $(
+            impl_scalar_tensor_op!(@op $t, Add, add, add);
+            impl_scalar_tensor_op!(@op $t, Sub, sub, sub);
+            impl_scalar_tensor_op!(@op $t, Mul, mul, mul_elem);
+            impl_scalar_tensor_op!(@op $t, Div, div, div);
+        )*
+    };
+    (@op $t:ty, $op:ident, $fn:ident, $method:ident) => {
+        impl<S> $op<&TensorBase<'_, $t, S>> for $t
+        where
+            S: TensorStorage<$t>,
+        {
+            type Output = Result<Tensor<'static, $t>, Error>;
+
+            fn $fn(self, other: &TensorBase<'_, $t, S>) -> Self::Output {
+                scalar_view(&self).$method(&other.view())
+            }
+        }
+
+        impl $op<Tensor<'_, $t>> for $t {
+            type Output = Result<Tensor<'static, $t>, Error>;
+
+            fn $fn(self, other: Tensor<'_, $t>) -> Self::Output {
+                self.$fn(&other)
+            }
+        }
+    };
+}
+
+impl_scalar_tensor_op!(i8, u8, i16, u16, i32, u32, i64, u64, i128, isize, usize);
+impl_scalar_tensor_op!(f32, f64, Complex<f32>, Complex<f64>);
+
+#[cfg(feature = "half")]
+impl_scalar_tensor_op!(half::f16, half::bf16);
+
+impl<'a, U, S> DivAssign<U> for TensorBase<'a, U, S>
 where
     U: TensorTypeNumeric + 'static,
-    S1: TensorStorage<U>,
-    S2: TensorStorage<U>,
+    S: TensorStorage<U> + TensorStorageMut<U>,
 {
-    type Output = TensorBase<'static, U, Vec<U>>;
-
-    fn mul(self, other: &TensorBase<'_, U, S1>) -> Self::Output {
-        // TODO: Use TensorView::from()
-        let tensor = self.mul_alias(&other.view());
-        match tensor {
-            Ok(val) => return val,
-            Err(_) => Tensor {
-                shape: vec![],
-                strides: vec![],
-                offset: 0,
-                data: vec![],
-                _u: PhantomData,
-                _s: PhantomData,
-            },
+    /// Panics on integer division by zero or overflow. Use `div_scalar` to
+    /// handle the error.
+    fn div_assign(&mut self, other: U) {
+        if let Err(err) = self.div_scalar(other) {
+            panic!("{}", err);
         }
     }
 }
@@ -353,6 +718,44 @@ where
     }
 }
 
+impl<'a, U, S1, S2> MulAssign<&TensorBase<'_, U, S1>> for TensorBase<'a, U, S2>
+where
+    U: TensorTypeNumeric + 'static,
+    S1: TensorStorage<U>,
+    S2: TensorStorage<U> + TensorStorageMut<U>,
+{
+    /// Panics if `other` doesn't broadcast to the shape of `self`. Use
+    /// `mul_elem_inplace` to handle the error.
+    fn mul_assign(&mut self, other: &TensorBase<'_, U, S1>) {
+        if let Err(err) = self.mul_elem_inplace(&other.view()) {
+            panic!("{}", err);
+        }
+    }
+}
+
+impl<'a, U, S1, S2> DivAssign<&TensorBase<'_, U, S1>> for TensorBase<'a, U, S2>
+where
+    U: TensorTypeNumeric + 'static,
+    S1: TensorStorage<U>,
+    S2: TensorStorage<U> + TensorStorageMut<U>,
+{
+    /// Panics on shape mismatch or integer division by zero. Use
+    /// `div_inplace` to handle the error.
+    fn div_assign(&mut self, other: &TensorBase<'_, U, S1>) {
+        if let Err(err) = self.div_inplace(&other.view()) {
+            panic!("{}", err);
+        }
+    }
+}
+
+/// Computes the shape that all of `shapes` broadcast to, following the NumPy
+/// rules. No shapes broadcast to the shape of a 0-d tensor.
+pub fn broadcast_shapes(shapes: &[&[usize]]) -> Result<Vec<usize>, Error> {
+    shapes.iter().try_fold(Vec::new(), |shape_c, shape| {
+        TensorBase::<u8, Vec<u8>>::shape_bc(&shape_c, shape, false)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::{Error, TensorBase};
@@ -423,5 +826,13 @@ mod tests {
             TensorBase::<i32, Vec<i32>>::shape_bc(&shape_a, &shape_b, true),
             Err(Error::ShapeMismatchBroadcast { .. })
         ));
+
+        // Negative: (3) * (3x2): Vector operands are handled by matmul
+        let shape_a = vec![3];
+        let shape_b = vec![3, 2];
+        assert!(matches!(
+            TensorBase::<i32, Vec<i32>>::shape_bc(&shape_a, &shape_b, true),
+            Err(Error::ShapeMismatchBroadcast { .. })
+        ));
     }
 }
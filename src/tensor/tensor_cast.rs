@@ -0,0 +1,181 @@
+use super::*;
+use num_traits::{AsPrimitive, NumCast};
+use std::cmp::Ordering;
+
+/// `NumCast` conversion that also fails when a finite float overflows to
+/// infinity in a narrower float type (e.g. `1e300f64` to `f32`).
+fn cast_elem<U, V>(val: U) -> Option<V>
+where
+    U: TensorTypeNumeric,
+    V: TensorTypeNumeric,
+{
+    let val_c: V = NumCast::from(val)?;
+    let finite = |val: Option<f64>| val.is_some_and(f64::is_finite);
+
+    match finite(val.to_f64()) && !finite(val_c.to_f64()) {
+        true => None,
+        false => Some(val_c),
+    }
+}
+
+/// Casts `val` to `V` according to `policy`. Only `CastPolicy::Checked`
+/// returns `None`, for values that `V` can't represent.
+fn cast_policy<U, V>(val: U, policy: CastPolicy) -> Option<V>
+where
+    U: TensorTypeOrdered + AsPrimitive<V>,
+    V: TensorTypeOrdered + 'static,
+{
+    let val_c = match (cast_elem(val), policy) {
+        (Some(val_c), _) => val_c,
+        (None, CastPolicy::Checked) => return None,
+        // NaN is the only value that is neither below nor above zero
+        (None, CastPolicy::Saturating) => match val.partial_cmp(&U::default()) {
+            Some(Ordering::Less) => V::min_value(),
+            Some(_) => V::max_value(),
+            None => V::default(),
+        },
+        (None, CastPolicy::Wrapping) => val.as_(),
+    };
+    Some(val_c)
+}
+
+/// Converts a row-major position into the index of an element.
+pub(crate) fn unravel(mut pos: usize, shape: &[usize]) -> Vec<usize> {
+    let mut index = vec![0; shape.len()];
+    for dim in (0..shape.len()).rev() {
+        index[dim] = pos % shape[dim];
+        pos /= shape[dim];
+    }
+    index
+}
+
+impl<'a, U, S> TensorBase<'a, U, S>
+where
+    U: TensorTypeOrdered,
+    S: TensorStorage<U>,
+{
+    /// Converts the elements to `V`, failing on the first value that `V`
+    /// can't represent. Floats are truncated towards zero when cast to an
+    /// integer type.
+    #[inline(always)]
+    pub fn cast<V>(&self) -> Result<Tensor<'static, V>, Error>
+    where
+        V: TensorTypeOrdered,
+        U: AsPrimitive<V>,
+    {
+        self.cast_with(CastPolicy::Checked)
+    }
+
+    /// Converts the elements to `V`, handling values that `V` can't represent
+    /// according to `policy`.
+    pub fn cast_with<V>(&self, policy: CastPolicy) -> Result<Tensor<'static, V>, Error>
+    where
+        V: TensorTypeOrdered,
+        U: AsPrimitive<V>,
+    {
+        let mut data = Vec::with_capacity(self.nelems());
+
+        for (pos, val) in self.iter().enumerate() {
+            data.push(cast_policy(val, policy).ok_or_else(|| Error::InvalidCast {
+                index: unravel(pos, &self.shape),
+                value: val.to_string(),
+            })?);
+        }
+
+        Ok(Tensor {
+            strides: self.compute_strides(&self.shape),
+            shape: self.shape(),
+            offset: 0,
+            data,
+            _u: PhantomData,
+            _s: PhantomData,
+        })
+    }
+}
+
+impl<'a, U, S> TensorBase<'a, U, S>
+where
+    U: TensorTypeOrdered,
+    S: TensorStorage<U>,
+{
+    /// Converts the elements to complex numbers with parts of type `V` and a
+    /// zero imaginary part, failing on the first value that `V` can't
+    /// represent.
+    #[inline(always)]
+    pub fn to_complex<V>(&self) -> Result<Tensor<'static, Complex<V>>, Error>
+    where
+        V: TensorTypeFloat,
+        Complex<V>: TensorTypeComplex,
+        U: AsPrimitive<V>,
+    {
+        self.to_complex_with(CastPolicy::Checked)
+    }
+
+    /// Converts the elements to complex numbers with parts of type `V` and a
+    /// zero imaginary part, handling values that `V` can't represent
+    /// according to `policy`.
+    pub fn to_complex_with<V>(
+        &self,
+        policy: CastPolicy,
+    ) -> Result<Tensor<'static, Complex<V>>, Error>
+    where
+        V: TensorTypeFloat,
+        Complex<V>: TensorTypeComplex,
+        U: AsPrimitive<V>,
+    {
+        Ok(self
+            .cast_with::<V>(policy)?
+            .map(|re| Complex::new(re, V::zero())))
+    }
+}
+
+impl<'a, C, S> TensorBase<'a, C, S>
+where
+    C: TensorTypeComplex,
+    S: TensorStorage<C>,
+{
+    /// Converts the elements to complex numbers with parts of type `V`,
+    /// failing on the first value whose real or imaginary part `V` can't
+    /// represent.
+    #[inline(always)]
+    pub fn cast_complex<V>(&self) -> Result<Tensor<'static, Complex<V>>, Error>
+    where
+        V: TensorTypeFloat,
+        Complex<V>: TensorTypeComplex,
+        C::Real: AsPrimitive<V>,
+    {
+        self.cast_complex_with(CastPolicy::Checked)
+    }
+
+    /// Converts the elements to complex numbers with parts of type `V`,
+    /// handling parts that `V` can't represent according to `policy`.
+    pub fn cast_complex_with<V>(
+        &self,
+        policy: CastPolicy,
+    ) -> Result<Tensor<'static, Complex<V>>, Error>
+    where
+        V: TensorTypeFloat,
+        Complex<V>: TensorTypeComplex,
+        C::Real: AsPrimitive<V>,
+    {
+        let mut data = Vec::with_capacity(self.nelems());
+
+        for (pos, val) in self.iter().enumerate() {
+            let parts = cast_policy(val.re(), policy).zip(cast_policy(val.im(), policy));
+            let (re, im) = parts.ok_or_else(|| Error::InvalidCast {
+                index: unravel(pos, &self.shape),
+                value: val.to_string(),
+            })?;
+            data.push(Complex::new(re, im));
+        }
+
+        Ok(Tensor {
+            strides: self.compute_strides(&self.shape),
+            shape: self.shape(),
+            offset: 0,
+            data,
+            _u: PhantomData,
+            _s: PhantomData,
+        })
+    }
+}
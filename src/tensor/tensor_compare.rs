@@ -0,0 +1,176 @@
+use super::*;
+
+impl<'a, U, S> TensorBase<'a, U, S>
+where
+    U: TensorType,
+    S: TensorStorage<U>,
+{
+    /// Element-wise `==` with broadcasting, as opposed to `PartialEq::eq`
+    /// which compares whole tensors.
+    pub fn eq_elem(&self, tensor_b: &TensorView<'_, U>) -> Result<Tensor<'static, bool>, Error> {
+        self.zip_bc(tensor_b, |_, a, b| Ok(a == b))
+    }
+
+    /// Element-wise `!=` with broadcasting.
+    pub fn ne_elem(&self, tensor_b: &TensorView<'_, U>) -> Result<Tensor<'static, bool>, Error> {
+        self.zip_bc(tensor_b, |_, a, b| Ok(a != b))
+    }
+
+    /// Replaces the elements where `mask` is true with `value`. The mask is
+    /// broadcast to the shape of the tensor.
+    pub fn masked_fill(
+        &self,
+        mask: &TensorView<'_, bool>,
+        value: U,
+    ) -> Result<Tensor<'static, U>, Error> {
+        let mask_bc = mask.broadcast_view(&self.shape)?;
+
+        Ok(Tensor {
+            strides: self.compute_strides(&self.shape),
+            shape: self.shape(),
+            offset: 0,
+            data: self
+                .iter()
+                .zip(mask_bc.iter())
+                .map(|(val, fill)| if fill { value } else { val })
+                .collect(),
+            _u: PhantomData,
+            _s: PhantomData,
+        })
+    }
+
+    /// 1-D tensor of the elements where `mask` is true, in row-major order.
+    /// The tensor and the mask are broadcast against each other.
+    pub fn masked_select(&self, mask: &TensorView<'_, bool>) -> Result<Tensor<'static, U>, Error> {
+        let shape = broadcast_shapes(&[&self.shape, &mask.shape])?;
+        let view = self.broadcast_view(&shape)?;
+        let mask_bc = mask.broadcast_view(&shape)?;
+
+        let data: Vec<U> = view
+            .iter()
+            .zip(mask_bc.iter())
+            .filter_map(|(val, select)| select.then_some(val))
+            .collect();
+
+        Ok(Tensor {
+            shape: vec![data.len()],
+            strides: vec![1],
+            offset: 0,
+            data,
+            _u: PhantomData,
+            _s: PhantomData,
+        })
+    }
+}
+
+impl<'a, U, S> TensorBase<'a, U, S>
+where
+    U: TensorTypeOrdered,
+    S: TensorStorage<U>,
+{
+    /// Element-wise `<` with broadcasting.
+    pub fn lt(&self, tensor_b: &TensorView<'_, U>) -> Result<Tensor<'static, bool>, Error> {
+        self.zip_bc(tensor_b, |_, a, b| Ok(a < b))
+    }
+
+    /// Element-wise `<=` with broadcasting.
+    pub fn le(&self, tensor_b: &TensorView<'_, U>) -> Result<Tensor<'static, bool>, Error> {
+        self.zip_bc(tensor_b, |_, a, b| Ok(a <= b))
+    }
+
+    /// Element-wise `>` with broadcasting.
+    pub fn gt(&self, tensor_b: &TensorView<'_, U>) -> Result<Tensor<'static, bool>, Error> {
+        self.zip_bc(tensor_b, |_, a, b| Ok(a > b))
+    }
+
+    /// Element-wise `>=` with broadcasting.
+    pub fn ge(&self, tensor_b: &TensorView<'_, U>) -> Result<Tensor<'static, bool>, Error> {
+        self.zip_bc(tensor_b, |_, a, b| Ok(a >= b))
+    }
+}
+
+impl<'a, S> TensorBase<'a, bool, S>
+where
+    S: TensorStorage<bool>,
+{
+    /// Element-wise logical and with broadcasting.
+    pub fn and(&self, tensor_b: &TensorView<'_, bool>) -> Result<Tensor<'static, bool>, Error> {
+        self.zip_bc(tensor_b, |_, a, b| Ok(a && b))
+    }
+
+    /// Element-wise logical or with broadcasting.
+    pub fn or(&self, tensor_b: &TensorView<'_, bool>) -> Result<Tensor<'static, bool>, Error> {
+        self.zip_bc(tensor_b, |_, a, b| Ok(a || b))
+    }
+
+    /// Element-wise logical exclusive or with broadcasting.
+    pub fn xor(&self, tensor_b: &TensorView<'_, bool>) -> Result<Tensor<'static, bool>, Error> {
+        self.zip_bc(tensor_b, |_, a, b| Ok(a ^ b))
+    }
+
+    pub fn not(&self) -> Tensor<'static, bool> {
+        Tensor {
+            strides: self.compute_strides(&self.shape),
+            shape: self.shape(),
+            offset: 0,
+            data: self.iter().map(|val| !val).collect(),
+            _u: PhantomData,
+            _s: PhantomData,
+        }
+    }
+
+    /// Whether any element is true. False for an empty tensor.
+    pub fn any(&self) -> bool {
+        self.iter().any(|val| val)
+    }
+
+    /// Whether all elements are true. True for an empty tensor.
+    pub fn all(&self) -> bool {
+        self.iter().all(|val| val)
+    }
+
+    /// Logical or over the given axes. With `keepdims` the reduced axes are
+    /// retained with size 1.
+    pub fn any_axis(&self, axes: &[usize], keepdims: bool) -> Result<Tensor<'static, bool>, Error> {
+        self.reduce_impl(axes, keepdims, false, |acc, val| acc || val)
+    }
+
+    /// Logical and over the given axes. With `keepdims` the reduced axes are
+    /// retained with size 1.
+    pub fn all_axis(&self, axes: &[usize], keepdims: bool) -> Result<Tensor<'static, bool>, Error> {
+        self.reduce_impl(axes, keepdims, true, |acc, val| acc && val)
+    }
+}
+
+impl<'a, U> Tensor<'a, U>
+where
+    U: TensorType,
+{
+    /// Picks elements from `tensor_a` where `mask` is true and from `tensor_b`
+    /// elsewhere. All three operands are broadcast against each other.
+    pub fn where_(
+        mask: &TensorView<'_, bool>,
+        tensor_a: &TensorView<'_, U>,
+        tensor_b: &TensorView<'_, U>,
+    ) -> Result<Tensor<'static, U>, Error> {
+        let shape = broadcast_shapes(&[&mask.shape, &tensor_a.shape, &tensor_b.shape])?;
+        let mask_bc = mask.broadcast_view(&shape)?;
+        let view_a = tensor_a.broadcast_view(&shape)?;
+        let view_b = tensor_b.broadcast_view(&shape)?;
+
+        let data = mask_bc
+            .iter()
+            .zip(view_a.iter().zip(view_b.iter()))
+            .map(|(pick_a, (a, b))| if pick_a { a } else { b })
+            .collect();
+
+        Ok(Tensor {
+            strides: tensor_a.compute_strides(&shape),
+            shape,
+            offset: 0,
+            data,
+            _u: PhantomData,
+            _s: PhantomData,
+        })
+    }
+}
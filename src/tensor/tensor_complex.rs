@@ -0,0 +1,56 @@
+use super::*;
+
+impl<'a, C, S> TensorBase<'a, C, S>
+where
+    C: TensorTypeComplex,
+    S: TensorStorage<C>,
+{
+    /// View of the real or imaginary parts. Each complex element is a pair of
+    /// reals, so strides and offset are doubled and `part` selects the half.
+    fn parts_view(&'a self, part: usize) -> TensorView<'a, C::Real> {
+        TensorView {
+            shape: self.shape(),
+            strides: self.strides.iter().map(|&stride| stride * 2).collect(),
+            offset: self.offset * 2 + part,
+            data: C::as_parts(self.data.as_ref()),
+            _u: PhantomData,
+            _s: PhantomData,
+        }
+    }
+
+    /// Zero-copy view of the real parts.
+    #[inline(always)]
+    pub fn real(&'a self) -> TensorView<'a, C::Real> {
+        self.parts_view(0)
+    }
+
+    /// Zero-copy view of the imaginary parts.
+    #[inline(always)]
+    pub fn imag(&'a self) -> TensorView<'a, C::Real> {
+        self.parts_view(1)
+    }
+
+    pub fn conj(&self) -> Tensor<'static, C> {
+        self.map(C::conj)
+    }
+
+    /// Element-wise phase angle in radians.
+    pub fn arg(&self) -> Tensor<'static, C::Real> {
+        self.map(C::arg)
+    }
+
+    /// Conjugate transpose (Hermitian adjoint). As with `transpose`, all axes
+    /// are reversed.
+    pub fn conj_t(&self) -> Tensor<'static, C> {
+        let view = TensorView {
+            shape: self.shape.iter().rev().copied().collect(),
+            strides: self.strides.iter().rev().copied().collect(),
+            offset: self.offset,
+            data: self.data.as_ref(),
+            _u: PhantomData,
+            _s: PhantomData,
+        };
+
+        view.conj()
+    }
+}
@@ -1,23 +1,34 @@
+use num_traits::NumCast;
+use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 use std::ops::Range;
 
 use super::{
-    Error, Tensor, TensorBase, TensorStorage, TensorStorageMut, TensorTypeNumeric, TensorView,
-    TensorViewMut,
+    Error, Tensor, TensorBase, TensorCow, TensorStorage, TensorStorageMut, TensorType,
+    TensorTypeFloat, TensorTypeNumeric, TensorTypeOrdered, TensorView, TensorViewMut,
 };
 
 pub trait TensorFromNDim<T, U> {
     fn compute_shape(data: &T) -> Vec<usize>;
     fn compute_strides(shape: &[usize]) -> Vec<usize>;
     fn flatten_data(data: &T, data_flat: &mut Vec<U>);
+
+    /// Checks that every nested `Vec` has the length given by `shape` at its
+    /// depth. `index` is the position of `data` in the outermost `Vec`.
+    fn check_shape(data: &T, shape: &[usize], index: &mut Vec<usize>) -> Result<(), Error>;
 }
 
-impl<U: TensorTypeNumeric> TensorFromNDim<U, U> for U {
+impl<U: TensorType> TensorFromNDim<U, U> for U {
     fn compute_shape(_: &U) -> Vec<usize> {
         vec![]
     }
 
+    fn check_shape(_: &U, _: &[usize], _: &mut Vec<usize>) -> Result<(), Error> {
+        Ok(())
+    }
+
     fn compute_strides(_: &[usize]) -> Vec<usize> {
         vec![]
     }
@@ -30,7 +41,7 @@ impl<U: TensorTypeNumeric> TensorFromNDim<U, U> for U {
 impl<T, U> TensorFromNDim<Vec<T>, U> for Vec<T>
 where
     T: TensorFromNDim<T, U>,
-    U: TensorTypeNumeric,
+    U: TensorType,
 {
     fn compute_shape(data: &Vec<T>) -> Vec<usize> {
         if data.is_empty() {
@@ -57,19 +68,37 @@ where
             T::flatten_data(item, data_flat);
         }
     }
+
+    fn check_shape(data: &Vec<T>, shape: &[usize], index: &mut Vec<usize>) -> Result<(), Error> {
+        if data.len() != shape[0] {
+            return Err(Error::RaggedData {
+                index: index.clone(),
+                len: data.len(),
+                expected: shape[0],
+            });
+        }
+
+        for (i, item) in data.iter().enumerate() {
+            index.push(i);
+            T::check_shape(item, &shape[1..], index)?;
+            index.pop();
+        }
+
+        Ok(())
+    }
 }
 
 impl<'a, U> Tensor<'a, U>
 where
-    U: TensorTypeNumeric,
+    U: TensorType,
 {
     pub fn from_vec<T>(data_ndim: T) -> Result<Self, Error>
     where
         T: TensorFromNDim<T, U>,
     {
-        // TODO: Sanity check for data_ndim
         let mut data = Vec::new();
         let shape = T::compute_shape(&data_ndim);
+        T::check_shape(&data_ndim, &shape, &mut Vec::new())?;
         let strides = T::compute_strides(&shape);
         let offset = 0;
 
@@ -89,9 +118,9 @@ where
     where
         T: TensorFromNDim<T, U>,
     {
-        // TODO: Sanity check for data_ndim
         let mut data = Vec::new();
         let shape = T::compute_shape(&data_ndim);
+        T::check_shape(data_ndim, &shape, &mut Vec::new())?;
         let strides = T::compute_strides(&shape);
         let offset = 0;
 
@@ -124,8 +153,32 @@ where
         })
     }
 
-    pub fn from_shape(shape: &[usize], data: &Vec<U>) -> Result<Self, Error> {
-        // TODO: Sanity check for data_ndim
+    /// Builds a tensor with the given shape from its elements in row-major
+    /// order.
+    pub fn from_shape(shape: &[usize], data: Vec<U>) -> Result<Self, Error> {
+        if shape.iter().product::<usize>() != data.len() {
+            return Err(Error::DataLengthMismatch {
+                shape: shape.to_vec(),
+                len: data.len(),
+            });
+        }
+
+        let shape = shape.to_vec();
+        let strides = <Vec<U> as TensorFromNDim<Vec<U>, U>>::compute_strides(&shape);
+        let offset = 0;
+
+        Ok(Tensor {
+            shape,
+            strides,
+            offset,
+            data,
+            _u: PhantomData,
+            _s: PhantomData,
+        })
+    }
+
+    pub fn full(shape: &[usize], value: U) -> Result<Self, Error> {
+        let data: Vec<U> = vec![value; shape.iter().product()];
         let shape = shape.to_vec();
         let strides = <Vec<U> as TensorFromNDim<Vec<U>, U>>::compute_strides(&shape);
         let offset = 0;
@@ -134,35 +187,185 @@ where
             shape,
             strides,
             offset,
-            data: data.clone(),
+            data,
             _u: PhantomData,
             _s: PhantomData,
         })
     }
+
+    /// Builds a tensor by calling `f` with the index of every element, in
+    /// row-major order.
+    pub fn from_fn<F>(shape: &[usize], mut f: F) -> Result<Self, Error>
+    where
+        F: FnMut(&[usize]) -> U,
+    {
+        let nelems = shape.iter().product();
+        let mut data = Vec::with_capacity(nelems);
+        let mut index = vec![0; shape.len()];
+
+        for _ in 0..nelems {
+            data.push(f(&index));
+
+            for dim in (0..shape.len()).rev() {
+                index[dim] += 1;
+                if index[dim] < shape[dim] {
+                    break;
+                }
+                index[dim] = 0;
+            }
+        }
+
+        let shape = shape.to_vec();
+        let strides = <Vec<U> as TensorFromNDim<Vec<U>, U>>::compute_strides(&shape);
+
+        Ok(Tensor {
+            shape,
+            strides,
+            offset: 0,
+            data,
+            _u: PhantomData,
+            _s: PhantomData,
+        })
+    }
+}
+
+impl<'a, U> Tensor<'a, U>
+where
+    U: TensorTypeNumeric,
+{
+    #[inline(always)]
+    pub fn ones(shape: &[usize]) -> Result<Self, Error> {
+        Self::full(shape, U::one())
+    }
+
+    /// `n x m` matrix with ones on the main diagonal.
+    pub fn eye(n: usize, m: usize) -> Result<Self, Error> {
+        let mut tensor = Self::from_zeros(&[n, m])?;
+        for i in 0..n.min(m) {
+            tensor.data[i * m + i] = U::one();
+        }
+
+        Ok(tensor)
+    }
 }
 
-impl<U: TensorTypeNumeric> TensorStorage<U> for Vec<U> {
+impl<'a, U> Tensor<'a, U>
+where
+    U: TensorTypeOrdered,
+{
+    /// 1-D tensor with the values `start, start + step, ...` up to, but not
+    /// including, `stop`. `step` may be negative for signed types.
+    pub fn arange(start: U, stop: U, step: U) -> Result<Self, Error> {
+        let zero = U::default();
+
+        // NaN steps are rejected along with zero
+        if matches!(step.partial_cmp(&zero), None | Some(Ordering::Equal)) {
+            return Err(Error::InvalidParam {
+                err_msg: format!("arange step must be non-zero, got {}", step),
+            });
+        }
+
+        let nelems = match (start.to_f64(), stop.to_f64(), step.to_f64()) {
+            (Some(start), Some(stop), Some(step)) => ((stop - start) / step).ceil(),
+            _ => f64::NAN,
+        };
+
+        if !nelems.is_finite() {
+            return Err(Error::InvalidParam {
+                err_msg: format!("Invalid arange range {}..{} by {}", start, stop, step),
+            });
+        }
+        let nelems = nelems.max(0.0) as usize;
+
+        // Floats are computed as `start + i * step` so that rounding errors
+        // don't build up (as in NumPy). Integers accumulate the step instead,
+        // which is exact and can't overflow as every value lies between start
+        // and stop, unlike `i * step`. Only integers truncate 0.5 to zero.
+        let integral = <U as NumCast>::from(0.5) == Some(zero);
+        let mut data = Vec::with_capacity(nelems);
+        let mut val = start;
+
+        for i in 0..nelems {
+            match integral {
+                true => {
+                    data.push(val);
+                    if i + 1 < nelems {
+                        val += step;
+                    }
+                }
+                false => data.push(start + step * <U as NumCast>::from(i).unwrap()),
+            }
+        }
+
+        Self::from_shape(&[nelems], data)
+    }
+}
+
+impl<'a, F> Tensor<'a, F>
+where
+    F: TensorTypeFloat,
+{
+    /// 1-D tensor with `num` evenly spaced values from `start` to `stop`,
+    /// both included.
+    pub fn linspace(start: F, stop: F, num: usize) -> Result<Self, Error> {
+        let step = match num {
+            0 | 1 => F::zero(),
+            _ => (stop - start) / F::from(num - 1).unwrap(),
+        };
+
+        let mut data: Vec<F> = (0..num)
+            .map(|i| start + step * F::from(i).unwrap())
+            .collect();
+
+        // Hit the end point exactly
+        if num > 1 {
+            data[num - 1] = stop;
+        }
+
+        Self::from_shape(&[num], data)
+    }
+
+    /// 1-D tensor with `num` values from `base^start` to `base^stop`, evenly
+    /// spaced on a log scale.
+    pub fn logspace(start: F, stop: F, num: usize, base: F) -> Result<Self, Error> {
+        let mut tensor = Self::linspace(start, stop, num)?;
+        for val in tensor.data.iter_mut() {
+            *val = base.powf(*val);
+        }
+
+        Ok(tensor)
+    }
+}
+
+impl<U: TensorType> TensorStorage<U> for Vec<U> {
+    #[inline(always)]
+    fn get(&self, index: usize) -> U {
+        self[index]
+    }
+}
+
+impl<'a, U: TensorType> TensorStorage<U> for &'a [U] {
     #[inline(always)]
     fn get(&self, index: usize) -> U {
         self[index]
     }
 }
 
-impl<'a, U: TensorTypeNumeric> TensorStorage<U> for &'a [U] {
+impl<'a, U: TensorType> TensorStorage<U> for &'a mut [U] {
     #[inline(always)]
     fn get(&self, index: usize) -> U {
         self[index]
     }
 }
 
-impl<'a, U: TensorTypeNumeric> TensorStorage<U> for &'a mut [U] {
+impl<'a, U: TensorType> TensorStorage<U> for Cow<'a, [U]> {
     #[inline(always)]
     fn get(&self, index: usize) -> U {
         self[index]
     }
 }
 
-impl<U: TensorTypeNumeric> TensorStorageMut<U> for Vec<U> {
+impl<U: TensorType> TensorStorageMut<U> for Vec<U> {
     #[inline(always)]
     fn get(&self, index: usize) -> U {
         self[index]
@@ -174,7 +377,7 @@ impl<U: TensorTypeNumeric> TensorStorageMut<U> for Vec<U> {
     }
 }
 
-impl<'a, U: TensorTypeNumeric> TensorStorageMut<U> for &'a mut [U] {
+impl<'a, U: TensorType> TensorStorageMut<U> for &'a mut [U] {
     #[inline(always)]
     fn get(&self, index: usize) -> U {
         self[index]
@@ -188,12 +391,14 @@ impl<'a, U: TensorTypeNumeric> TensorStorageMut<U> for &'a mut [U] {
 
 impl<'a, U, S> TensorBase<'a, U, S>
 where
-    U: TensorTypeNumeric,
+    U: TensorType,
     S: TensorStorage<U>,
 {
+    /// Returns the elements of the tensor in row-major order. For views this
+    /// is only the viewed elements, not the whole backing buffer.
     #[inline(always)]
     pub fn data(&self) -> Vec<U> {
-        self.data.as_ref().to_vec()
+        self.iter().collect()
     }
 
     #[inline(always)]
@@ -211,9 +416,65 @@ where
         self.strides.len()
     }
 
+    /// Number of elements in the tensor, which may be fewer than in the
+    /// backing buffer of a view.
     #[inline(always)]
     pub fn nelems(&self) -> usize {
-        self.data.as_ref().len()
+        self.shape.iter().product()
+    }
+
+    /// Whether the elements are laid out in row-major order without gaps,
+    /// i.e. the strides are those of a compact tensor of the same shape.
+    /// Size-1 axes are ignored, as their stride is never used.
+    pub fn is_contiguous(&self) -> bool {
+        if self.shape.contains(&0) {
+            return true;
+        }
+
+        let mut stride_c = 1;
+        for (&size, &stride) in self.shape.iter().zip(&self.strides).rev() {
+            if size != 1 && stride != stride_c {
+                return false;
+            }
+            stride_c *= size;
+        }
+
+        true
+    }
+
+    /// Copies the elements into a new compact tensor in row-major order.
+    pub fn to_owned(&self) -> Tensor<'static, U> {
+        Tensor {
+            strides: self.compute_strides(&self.shape),
+            shape: self.shape(),
+            offset: 0,
+            data: self.iter().collect(),
+            _u: PhantomData,
+            _s: PhantomData,
+        }
+    }
+
+    /// Borrows the data if the tensor is contiguous and copies it into a
+    /// compact tensor otherwise.
+    pub fn to_contiguous(&'a self) -> TensorCow<'a, U> {
+        match self.is_contiguous() {
+            true => TensorCow {
+                shape: self.shape(),
+                strides: self.strides(),
+                offset: self.offset,
+                data: Cow::Borrowed(self.data.as_ref()),
+                _u: PhantomData,
+                _s: PhantomData,
+            },
+            false => TensorCow {
+                strides: self.compute_strides(&self.shape),
+                shape: self.shape(),
+                offset: 0,
+                data: Cow::Owned(self.iter().collect()),
+                _u: PhantomData,
+                _s: PhantomData,
+            },
+        }
     }
 
     pub fn compute_strides(&self, shape: &[usize]) -> Vec<usize> {
@@ -237,7 +498,7 @@ where
             flat_index += idx * self.strides[i];
         }
 
-        if flat_index >= self.nelems() {
+        if flat_index >= self.data.len() {
             return Err(Error::IndexOutOfRange {
                 index: flat_index,
                 nelems: self.ndim(),
@@ -419,49 +680,241 @@ where
         self.transpose()
     }
 
-    fn flatten_impl(&'a self) -> Result<(Vec<usize>, Vec<usize>), Error> {
-        let mut shape_fl = vec![self.shape[0], 0];
-        shape_fl[1] = self.shape.iter().skip(1).product();
-        let strides_fl = vec![shape_fl[1], 1];
+    fn reshape_infer(&self, shape: &[isize]) -> Result<Vec<usize>, Error> {
+        let nelems: usize = self.shape.iter().product();
+        let mut infer = None;
+        let mut known = 1;
+
+        for (dim, &size) in shape.iter().enumerate() {
+            match size {
+                -1 if infer.is_none() => infer = Some(dim),
+                _ if size < 0 => {
+                    return Err(Error::InvalidParam {
+                        err_msg: format!("Invalid reshape dimensions {:?}", shape),
+                    })
+                }
+                _ => known *= size as usize,
+            }
+        }
+
+        let mut shape_r: Vec<usize> = shape.iter().map(|&size| size.max(0) as usize).collect();
+
+        if let Some(dim) = infer {
+            if known == 0 || !nelems.is_multiple_of(known) {
+                return Err(Error::InvalidParam {
+                    err_msg: format!("Cannot infer -1 in {:?} for shape {:?}", shape, self.shape),
+                });
+            }
+            shape_r[dim] = nelems / known;
+        }
+
+        if shape_r.iter().product::<usize>() != nelems {
+            return Err(Error::ShapeMismatch {
+                shape_a: self.shape(),
+                shape_b: shape_r,
+            });
+        }
+
+        Ok(shape_r)
+    }
+
+    /// Computes the strides for viewing the elements with `shape` without
+    /// copying, or `None` if the current strides don't allow it. Every group
+    /// of axes that is merged or split has to be contiguous in memory.
+    fn reshape_strides(&self, shape: &[usize]) -> Option<Vec<usize>> {
+        // Size-1 axes don't constrain the memory layout
+        let (shape_o, strides_o): (Vec<usize>, Vec<usize>) = self
+            .shape
+            .iter()
+            .zip(&self.strides)
+            .filter(|(&size, _)| size != 1)
+            .unzip();
+
+        if shape.contains(&0) || shape_o.contains(&0) {
+            return None;
+        }
+
+        let mut strides = vec![1; shape.len()];
+        let (mut oi, mut ni) = (0, 0);
+
+        while oi < shape_o.len() && ni < shape.len() {
+            let (mut oj, mut nj) = (oi + 1, ni + 1);
+            let (mut size_o, mut size_n) = (shape_o[oi], shape[ni]);
+
+            while size_o != size_n {
+                if size_n < size_o {
+                    size_n *= shape[nj];
+                    nj += 1;
+                } else {
+                    size_o *= shape_o[oj];
+                    oj += 1;
+                }
+            }
+
+            for ok in oi..oj - 1 {
+                if strides_o[ok] != shape_o[ok + 1] * strides_o[ok + 1] {
+                    return None;
+                }
+            }
+
+            strides[nj - 1] = strides_o[oj - 1];
+            for nk in (ni + 1..nj).rev() {
+                strides[nk - 1] = strides[nk] * shape[nk];
+            }
+
+            (oi, ni) = (oj, nj);
+        }
+
+        // Trailing size-1 axes
+        for nk in ni..shape.len() {
+            strides[nk] = match nk {
+                0 => 1,
+                _ => strides[nk - 1],
+            };
+        }
+
+        Some(strides)
+    }
+
+    fn reshape_cow(&'a self, shape: Vec<usize>) -> TensorCow<'a, U> {
+        match self.reshape_strides(&shape) {
+            Some(strides) => TensorCow {
+                shape,
+                strides,
+                offset: self.offset,
+                data: Cow::Borrowed(self.data.as_ref()),
+                _u: PhantomData,
+                _s: PhantomData,
+            },
+            None => TensorCow {
+                strides: self.compute_strides(&shape),
+                shape,
+                offset: 0,
+                data: Cow::Owned(self.iter().collect()),
+                _u: PhantomData,
+                _s: PhantomData,
+            },
+        }
+    }
+
+    /// Gives the elements a new shape. One dimension may be `-1`, in which case
+    /// it is inferred from the number of elements. The result borrows the
+    /// data when the strides allow it, and holds a row-major copy otherwise
+    /// (e.g. for a transposed view).
+    pub fn reshape(&'a self, shape: &[isize]) -> Result<TensorCow<'a, U>, Error> {
+        let shape = self.reshape_infer(shape)?;
+        Ok(self.reshape_cow(shape))
+    }
+
+    fn flatten_impl(&self, start_dim: usize, end_dim: usize) -> Result<Vec<usize>, Error> {
+        // A 0-d tensor is flattened to a single element vector
+        if self.ndim() == 0 {
+            return Ok(vec![1]);
+        }
+
+        for axis in [start_dim, end_dim] {
+            if axis >= self.ndim() {
+                return Err(Error::InvalidAxis {
+                    axis,
+                    ndim: self.ndim(),
+                });
+            }
+        }
+
+        if start_dim > end_dim {
+            return Err(Error::InvalidParam {
+                err_msg: format!("start_dim ({}) > end_dim ({})", start_dim, end_dim),
+            });
+        }
+
+        let mut shape_fl = self.shape[..start_dim].to_vec();
+        shape_fl.push(self.shape[start_dim..=end_dim].iter().product());
+        shape_fl.extend_from_slice(&self.shape[end_dim + 1..]);
+
+        Ok(shape_fl)
+    }
+
+    /// Merges the axes `start_dim..=end_dim` into one. Borrows the data when
+    /// the strides allow it and copies otherwise, like `reshape`.
+    pub fn flatten(&'a self, start_dim: usize, end_dim: usize) -> Result<TensorCow<'a, U>, Error> {
+        let shape = self.flatten_impl(start_dim, end_dim)?;
+        Ok(self.reshape_cow(shape))
+    }
 
-        Ok((shape_fl, strides_fl))
+    fn squeeze_impl(&self, axis: usize) -> Result<(Vec<usize>, Vec<usize>), Error> {
+        if axis >= self.ndim() {
+            return Err(Error::InvalidAxis {
+                axis,
+                ndim: self.ndim(),
+            });
+        }
+
+        if self.shape[axis] != 1 {
+            return Err(Error::InvalidParam {
+                err_msg: format!("Cannot squeeze axis {} of shape {:?}", axis, self.shape),
+            });
+        }
+
+        let mut shape = self.shape();
+        let mut strides = self.strides();
+        shape.remove(axis);
+        strides.remove(axis);
+
+        Ok((shape, strides))
     }
 
-    pub fn flatten(&'a self) -> Result<TensorView<'a, U>, Error> {
-        let (shape, strides) = self.flatten_impl()?;
+    /// Removes `axis`, which must have size 1.
+    pub fn squeeze(&'a self, axis: usize) -> Result<TensorView<'a, U>, Error> {
+        let (shape, strides) = self.squeeze_impl(axis)?;
 
         Ok(TensorView {
             shape,
             strides,
-            offset: 0,
-            data: &self.data.as_ref(),
+            offset: self.offset,
+            data: self.data.as_ref(),
             _u: PhantomData,
             _s: PhantomData,
         })
     }
 
-    pub fn max(&self) -> U {
-        let mut max = U::min_value();
-
-        for val in self.iter() {
-            if val > max {
-                max = val;
-            }
+    fn unsqueeze_impl(&self, axis: usize) -> Result<(Vec<usize>, Vec<usize>), Error> {
+        if axis > self.ndim() {
+            return Err(Error::InvalidAxis {
+                axis,
+                ndim: self.ndim(),
+            });
         }
 
-        max
+        let stride = match axis {
+            _ if axis < self.ndim() => self.shape[axis] * self.strides[axis],
+            _ => 1,
+        };
+
+        let mut shape = self.shape();
+        let mut strides = self.strides();
+        shape.insert(axis, 1);
+        strides.insert(axis, stride);
+
+        Ok((shape, strides))
     }
 
-    pub fn min(&self) -> U {
-        let mut min = U::max_value();
+    /// Inserts a size-1 axis at position `axis` (`0..=ndim`).
+    pub fn unsqueeze(&'a self, axis: usize) -> Result<TensorView<'a, U>, Error> {
+        let (shape, strides) = self.unsqueeze_impl(axis)?;
 
-        for val in self.iter() {
-            if val < min {
-                min = val;
-            }
-        }
+        Ok(TensorView {
+            shape,
+            strides,
+            offset: self.offset,
+            data: self.data.as_ref(),
+            _u: PhantomData,
+            _s: PhantomData,
+        })
+    }
 
-        min
+    #[inline(always)]
+    pub fn expand_dims(&'a self, axis: usize) -> Result<TensorView<'a, U>, Error> {
+        self.unsqueeze(axis)
     }
 
     pub fn print(&self) -> String {
@@ -471,7 +924,7 @@ where
             indices: &mut Vec<usize>,
         ) -> String
         where
-            U: TensorTypeNumeric,
+            U: TensorType,
             S: TensorStorage<U> + AsRef<[U]>,
         {
             if depth == view.ndim() {
@@ -507,7 +960,44 @@ where
 
 impl<'a, U, S> TensorBase<'a, U, S>
 where
-    U: TensorTypeNumeric,
+    U: TensorTypeOrdered,
+    S: TensorStorage<U>,
+{
+    /// Largest element, reduced like `max_axis` over every axis. NaN
+    /// propagates and an empty tensor is an error.
+    pub fn max(&self) -> Result<U, Error>
+    where
+        U: 'static,
+    {
+        self.check_nonempty()?;
+        let axes: Vec<usize> = (0..self.ndim()).collect();
+        Ok(self.max_axis(&axes, false)?.data[0])
+    }
+
+    /// Smallest element, reduced like `min_axis` over every axis. NaN
+    /// propagates and an empty tensor is an error.
+    pub fn min(&self) -> Result<U, Error>
+    where
+        U: 'static,
+    {
+        self.check_nonempty()?;
+        let axes: Vec<usize> = (0..self.ndim()).collect();
+        Ok(self.min_axis(&axes, false)?.data[0])
+    }
+
+    fn check_nonempty(&self) -> Result<(), Error> {
+        match self.nelems() {
+            0 => Err(Error::InvalidParam {
+                err_msg: format!("Tensor of shape {:?} is empty", self.shape),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<'a, U, S> TensorBase<'a, U, S>
+where
+    U: TensorType,
     S: TensorStorage<U> + TensorStorageMut<U>,
 {
     pub fn setval(&mut self, index: &[usize], val: U) -> Result<bool, Error> {
@@ -519,7 +1009,7 @@ where
             flat_index += idx * self.strides[i];
         }
 
-        if flat_index >= self.nelems() {
+        if flat_index >= self.data.len() {
             return Err(Error::IndexOutOfRange {
                 index: flat_index,
                 nelems: self.ndim(),
@@ -599,13 +1089,74 @@ where
         self.transpose_mut()
     }
 
-    pub fn flatten_mut(&'a mut self) -> Result<TensorViewMut<'a, U>, Error> {
-        let (shape, strides) = self.flatten_impl()?;
+    fn reshape_mut_impl(&self, shape: Vec<usize>) -> Result<(Vec<usize>, Vec<usize>), Error> {
+        match self.reshape_strides(&shape) {
+            Some(strides) => Ok((shape, strides)),
+            None => Err(Error::InvalidParam {
+                err_msg: format!(
+                    "Cannot reshape {:?} with strides {:?} to {:?} without copying",
+                    self.shape, self.strides, shape
+                ),
+            }),
+        }
+    }
+
+    /// Mutable counterpart of `reshape`. Fails if the strides don't allow a
+    /// view, as changes to a copy wouldn't reach the tensor.
+    pub fn reshape_mut(&'a mut self, shape: &[isize]) -> Result<TensorViewMut<'a, U>, Error> {
+        let shape = self.reshape_infer(shape)?;
+        let (shape, strides) = self.reshape_mut_impl(shape)?;
 
         Ok(TensorViewMut {
             shape,
             strides,
-            offset: 0,
+            offset: self.offset,
+            data: self.data.as_mut(),
+            _u: PhantomData,
+            _s: PhantomData,
+        })
+    }
+
+    /// Mutable counterpart of `flatten`. Fails if the strides don't allow a
+    /// view.
+    pub fn flatten_mut(
+        &'a mut self,
+        start_dim: usize,
+        end_dim: usize,
+    ) -> Result<TensorViewMut<'a, U>, Error> {
+        let shape = self.flatten_impl(start_dim, end_dim)?;
+        let (shape, strides) = self.reshape_mut_impl(shape)?;
+
+        Ok(TensorViewMut {
+            shape,
+            strides,
+            offset: self.offset,
+            data: self.data.as_mut(),
+            _u: PhantomData,
+            _s: PhantomData,
+        })
+    }
+
+    pub fn squeeze_mut(&'a mut self, axis: usize) -> Result<TensorViewMut<'a, U>, Error> {
+        let (shape, strides) = self.squeeze_impl(axis)?;
+
+        Ok(TensorViewMut {
+            shape,
+            strides,
+            offset: self.offset,
+            data: self.data.as_mut(),
+            _u: PhantomData,
+            _s: PhantomData,
+        })
+    }
+
+    pub fn unsqueeze_mut(&'a mut self, axis: usize) -> Result<TensorViewMut<'a, U>, Error> {
+        let (shape, strides) = self.unsqueeze_impl(axis)?;
+
+        Ok(TensorViewMut {
+            shape,
+            strides,
+            offset: self.offset,
             data: self.data.as_mut(),
             _u: PhantomData,
             _s: PhantomData,
@@ -615,7 +1166,7 @@ where
 
 impl<'a, U> TensorViewMut<'a, U>
 where
-    U: TensorTypeNumeric,
+    U: TensorType,
 {
     pub fn update<T>(
         &'a mut self,
@@ -635,7 +1186,7 @@ where
 
 impl<'a, U> Tensor<'a, U>
 where
-    U: TensorTypeNumeric,
+    U: TensorType,
 {
     pub fn update<T>(
         &'a mut self,
@@ -657,7 +1208,7 @@ pub trait TensorUpdate<T, U> {
     fn _update(view: &mut TensorViewMut<U>, index: &mut Vec<usize>, data: &T);
 }
 
-impl<U: TensorTypeNumeric> TensorUpdate<U, U> for U {
+impl<U: TensorType> TensorUpdate<U, U> for U {
     fn _update(view: &mut TensorViewMut<U>, index: &mut Vec<usize>, data: &U) {
         view.setval(index, *data).unwrap();
     }
@@ -666,7 +1217,7 @@ impl<U: TensorTypeNumeric> TensorUpdate<U, U> for U {
 impl<T, U> TensorUpdate<Vec<T>, U> for Vec<T>
 where
     T: TensorUpdate<T, U> + Debug,
-    U: TensorTypeNumeric,
+    U: TensorType,
 {
     fn _update(view: &mut TensorViewMut<U>, index: &mut Vec<usize>, data: &Vec<T>) {
         // TODO: Verify the length of data is equal to the slice length
@@ -682,7 +1233,7 @@ where
     }
 }
 
-impl<'a, U: TensorTypeNumeric> From<&'a Tensor<'a, U>> for TensorView<'a, U> {
+impl<'a, U: TensorType> From<&'a Tensor<'a, U>> for TensorView<'a, U> {
     fn from(item: &'a Tensor<U>) -> Self {
         TensorView {
             shape: item.shape(),
@@ -695,7 +1246,7 @@ impl<'a, U: TensorTypeNumeric> From<&'a Tensor<'a, U>> for TensorView<'a, U> {
     }
 }
 
-impl<'a, U: TensorTypeNumeric> From<&'a mut Tensor<'a, U>> for TensorViewMut<'a, U> {
+impl<'a, U: TensorType> From<&'a mut Tensor<'a, U>> for TensorViewMut<'a, U> {
     fn from(item: &'a mut Tensor<U>) -> Self {
         TensorViewMut {
             shape: item.shape(),
@@ -710,14 +1261,14 @@ impl<'a, U: TensorTypeNumeric> From<&'a mut Tensor<'a, U>> for TensorViewMut<'a,
 
 impl<U> Clone for Tensor<'_, U>
 where
-    U: TensorTypeNumeric + 'static,
+    U: TensorType + 'static,
 {
     fn clone(&self) -> Self {
         Tensor {
             shape: self.shape(),
             strides: self.strides(),
             offset: self.offset,
-            data: self.data(),
+            data: self.data.clone(),
             _u: PhantomData,
             _s: PhantomData,
         }
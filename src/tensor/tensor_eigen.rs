@@ -0,0 +1,884 @@
+use super::*;
+use num_traits::{Float, NumCast};
+use std::cmp::Ordering;
+use std::ops::Range;
+use tensor_cast::unravel;
+use tensor_linalg::{identity, QrFactors};
+
+/// Sweeps of Jacobi rotations before giving up on convergence.
+const MAX_SWEEPS: usize = 100;
+
+/// Iterations of the shifted QR algorithm per eigenvalue before giving up.
+const MAX_QR_ITERS: usize = 30;
+
+/// Iterations without deflation between exceptional shifts.
+const EXCEPTIONAL_SHIFT_ITERS: usize = 10;
+
+fn transpose<F: Copy>(a: &[F], rows: usize, cols: usize) -> Vec<F> {
+    (0..rows * cols)
+        .map(|pos| a[(pos % rows) * cols + pos / rows])
+        .collect()
+}
+
+/// Tangent of the Jacobi rotation angle `phi` with `cot(2 * phi) = theta`,
+/// taking the smaller root for stability.
+#[inline(always)]
+fn jacobi_tan<F: TensorTypeFloat>(theta: F) -> F {
+    let sign = match theta >= F::zero() {
+        true => F::one(),
+        false => -F::one(),
+    };
+    sign / (Float::abs(theta) + Float::hypot(theta, F::one()))
+}
+
+/// Right-multiplies the row-major matrix `x` with `n` columns by the rotation
+/// acting on columns `p` and `q`.
+fn rotate_cols<F: TensorTypeFloat>(x: &mut [F], n: usize, p: usize, q: usize, c: F, s: F) {
+    for row in x.chunks_mut(n) {
+        let (xp, xq) = (row[p], row[q]);
+        row[p] = c * xp - s * xq;
+        row[q] = s * xp + c * xq;
+    }
+}
+
+/// Left-multiplies the row-major matrix `x` with `n` columns by the
+/// transposed rotation acting on rows `p` and `q`.
+fn rotate_rows<F: TensorTypeFloat>(x: &mut [F], n: usize, p: usize, q: usize, c: F, s: F) {
+    for k in 0..n {
+        let (xp, xq) = (x[p * n + k], x[q * n + k]);
+        x[p * n + k] = c * xp - s * xq;
+        x[q * n + k] = s * xp + c * xq;
+    }
+}
+
+/// Order of `vals` sorted descending, NaN last.
+fn order_desc<F: TensorTypeFloat>(vals: &[F]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..vals.len()).collect();
+    order.sort_by(|&i, &j| vals[j].partial_cmp(&vals[i]).unwrap_or(Ordering::Equal));
+    order
+}
+
+/// Eigenvalues in ascending order and the matching eigenvectors (as columns)
+/// of a symmetric row-major `n x n` matrix, by cyclic Jacobi rotations. Only
+/// the lower triangle is read. `None` if the rotations don't converge.
+fn jacobi_eigh<F: TensorTypeFloat>(a: &[F], n: usize) -> Option<(Vec<F>, Vec<F>)> {
+    if a.iter().any(|val| !val.is_finite()) {
+        return None;
+    }
+
+    let mut a: Vec<F> = (0..n * n)
+        .map(|pos| match pos % n <= pos / n {
+            true => a[pos],
+            false => a[(pos % n) * n + pos / n],
+        })
+        .collect();
+    let mut v = identity(n);
+    let total = a.iter().fold(F::zero(), |acc, &val| acc + val * val);
+    let tol = F::epsilon() * F::epsilon() * total;
+    let mut converged = false;
+
+    for _ in 0..MAX_SWEEPS {
+        let mut off = F::zero();
+        for i in 0..n {
+            for j in 0..n {
+                if i != j {
+                    off += a[i * n + j] * a[i * n + j];
+                }
+            }
+        }
+        if off <= tol {
+            converged = true;
+            break;
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let apq = a[p * n + q];
+                if apq == F::zero() {
+                    continue;
+                }
+
+                let t = jacobi_tan((a[q * n + q] - a[p * n + p]) / (apq + apq));
+                let c = Float::recip(Float::hypot(t, F::one()));
+                let s = t * c;
+                rotate_cols(&mut a, n, p, q, c, s);
+                rotate_rows(&mut a, n, p, q, c, s);
+                rotate_cols(&mut v, n, p, q, c, s);
+                a[p * n + q] = F::zero();
+                a[q * n + p] = F::zero();
+            }
+        }
+    }
+
+    if !converged {
+        return None;
+    }
+
+    let diag: Vec<F> = (0..n).map(|i| a[i * n + i]).collect();
+    let order: Vec<usize> = order_desc(&diag).into_iter().rev().collect();
+    let w = order.iter().map(|&i| diag[i]).collect();
+    let v = (0..n * n)
+        .map(|pos| v[(pos / n) * n + order[pos % n]])
+        .collect();
+    Some((w, v))
+}
+
+/// Singular value decomposition of a row-major `m x n` matrix with `m >= n`
+/// by one-sided Jacobi rotations. Returns `U` (`m x n`) with zero columns for
+/// zero singular values, the singular values in descending order and `V`
+/// (`n x n`). `None` if the rotations don't converge.
+fn jacobi_svd<F: TensorTypeFloat>(a: &[F], m: usize, n: usize) -> Option<(Vec<F>, Vec<F>, Vec<F>)> {
+    if a.iter().any(|val| !val.is_finite()) {
+        return None;
+    }
+
+    let mut g = a.to_vec();
+    let mut v = identity(n);
+    let mut converged = false;
+
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let (mut alpha, mut beta, mut gamma) = (F::zero(), F::zero(), F::zero());
+                for row in g.chunks(n) {
+                    alpha += row[p] * row[p];
+                    beta += row[q] * row[q];
+                    gamma += row[p] * row[q];
+                }
+                // Columns are already orthogonal to working precision
+                if Float::abs(gamma) <= F::epsilon() * Float::sqrt(alpha * beta) {
+                    continue;
+                }
+
+                rotated = true;
+                let t = jacobi_tan((beta - alpha) / (gamma + gamma));
+                let c = Float::recip(Float::hypot(t, F::one()));
+                let s = t * c;
+                rotate_cols(&mut g, n, p, q, c, s);
+                rotate_cols(&mut v, n, p, q, c, s);
+            }
+        }
+
+        if !rotated {
+            converged = true;
+            break;
+        }
+    }
+
+    if !converged {
+        return None;
+    }
+
+    let sigma: Vec<F> = (0..n)
+        .map(|j| {
+            Float::sqrt(
+                g.chunks(n)
+                    .fold(F::zero(), |acc, row| acc + row[j] * row[j]),
+            )
+        })
+        .collect();
+    let order = order_desc(&sigma);
+    let s: Vec<F> = order.iter().map(|&j| sigma[j]).collect();
+
+    let u = (0..m * n)
+        .map(|pos| {
+            let (i, j) = (pos / n, order[pos % n]);
+            match sigma[j] > F::zero() {
+                true => g[i * n + j] / sigma[j],
+                false => F::zero(),
+            }
+        })
+        .collect();
+    let v = (0..n * n)
+        .map(|pos| v[(pos / n) * n + order[pos % n]])
+        .collect();
+
+    Some((u, s, v))
+}
+
+/// Copies the row-major `m x k` matrix `u`, whose first `rank` columns are
+/// orthonormal, into an `m x cols` matrix and fills the columns from `rank`
+/// on with an orthonormal basis of their complement.
+fn complete_basis<F: TensorTypeFloat>(
+    u: &[F],
+    m: usize,
+    k: usize,
+    rank: usize,
+    cols: usize,
+) -> Vec<F> {
+    let mut basis = vec![F::zero(); m * cols];
+    for i in 0..m {
+        basis[i * cols..i * cols + rank].copy_from_slice(&u[i * k..i * k + rank]);
+    }
+    if rank == cols {
+        return basis;
+    }
+
+    // The trailing columns of a complete Q are orthogonal to the leading ones
+    let leading = (0..m * rank)
+        .map(|pos| u[(pos / rank) * k + pos % rank])
+        .collect();
+    let q = QrFactors::new(leading, m, rank).q(cols);
+    for i in 0..m {
+        basis[i * cols + rank..(i + 1) * cols].copy_from_slice(&q[i * cols + rank..(i + 1) * cols]);
+    }
+    basis
+}
+
+/// Singular values below this are treated as zero, as in NumPy. `s` is in
+/// descending order.
+fn rank_tol<F: TensorTypeFloat>(s: &[F], m: usize, n: usize) -> F {
+    let size = <F as NumCast>::from(m.max(n)).unwrap_or_else(F::one);
+    s.first().copied().unwrap_or_else(F::zero) * size * F::epsilon()
+}
+
+/// SVD factors of a single matrix, `A = U * diag(s) * Vt`.
+struct SvdFactors<F> {
+    u: Vec<F>,
+    s: Vec<F>,
+    vt: Vec<F>,
+}
+
+/// SVD of a row-major `m x n` matrix. With `full_matrices` `U` is `m x m` and
+/// `Vt` is `n x n`, otherwise they are `m x k` and `k x n` with
+/// `k = min(m, n)`.
+fn svd_matrix<F: TensorTypeFloat>(
+    a: &[F],
+    m: usize,
+    n: usize,
+    full_matrices: bool,
+) -> Option<SvdFactors<F>> {
+    let k = m.min(n);
+
+    // A wide matrix is handled through its transpose, A^T = U * S * V^T
+    let (g, s, v) = match m >= n {
+        true => jacobi_svd(a, m, n)?,
+        false => jacobi_svd(&transpose(a, m, n), n, m)?,
+    };
+    let rank = s.iter().filter(|&&val| val > F::zero()).count();
+    let (rows, cols) = (m.max(n), if full_matrices { m.max(n) } else { k });
+    let g = complete_basis(&g, rows, k, rank, cols);
+
+    Some(match m >= n {
+        true => SvdFactors {
+            u: g,
+            s,
+            vt: transpose(&v, n, n),
+        },
+        false => SvdFactors {
+            u: v,
+            s,
+            vt: transpose(&g, n, cols),
+        },
+    })
+}
+
+// The Hessenberg reduction and the Francis QR iteration follow Golub & Van
+// Loan, Matrix Computations, §7.4 and §7.5.
+
+/// Householder vector `v` and `beta = 2 / (v^T * v)` such that
+/// `(I - beta * v * v^T) * x` is a multiple of the first unit vector. The
+/// multiple takes the opposite sign of `x[0]` to avoid cancellation. `None`
+/// if `x` already is such a multiple.
+fn house<F: TensorTypeFloat>(x: &[F]) -> Option<(Vec<F>, F)> {
+    let tail = x[1..].iter().fold(F::zero(), |acc, &val| acc + val * val);
+    if tail == F::zero() {
+        return None;
+    }
+
+    let norm = Float::sqrt(x[0] * x[0] + tail);
+    let mut v = x.to_vec();
+    v[0] = match x[0] < F::zero() {
+        true => x[0] - norm,
+        false => x[0] + norm,
+    };
+    let vnorm2 = v[0] * v[0] + tail;
+    Some((v, (F::one() + F::one()) / vnorm2))
+}
+
+/// Applies `I - beta * v * v^T` from the left to rows `row..row + v.len()`
+/// of the columns `cols` of the row-major matrix `h` with `n` columns.
+fn reflect_left<F: TensorTypeFloat>(
+    h: &mut [F],
+    n: usize,
+    (v, beta): (&[F], F),
+    row: usize,
+    cols: Range<usize>,
+) {
+    for c in cols {
+        let dot = v
+            .iter()
+            .enumerate()
+            .fold(F::zero(), |acc, (i, &vi)| acc + vi * h[(row + i) * n + c]);
+        for (i, &vi) in v.iter().enumerate() {
+            h[(row + i) * n + c] -= beta * dot * vi;
+        }
+    }
+}
+
+/// Applies `I - beta * v * v^T` from the right to columns `col..col +
+/// v.len()` of the rows `rows` of the row-major matrix `h` with `n` columns.
+fn reflect_right<F: TensorTypeFloat>(
+    h: &mut [F],
+    n: usize,
+    (v, beta): (&[F], F),
+    col: usize,
+    rows: Range<usize>,
+) {
+    for r in rows {
+        let row = &mut h[r * n + col..r * n + col + v.len()];
+        let dot = v
+            .iter()
+            .zip(row.iter())
+            .fold(F::zero(), |acc, (&vi, &val)| acc + vi * val);
+        for (val, &vi) in row.iter_mut().zip(v) {
+            *val -= beta * dot * vi;
+        }
+    }
+}
+
+/// Reduces a row-major `n x n` matrix to upper Hessenberg form by Householder
+/// similarity transformations.
+fn hessenberg<F: TensorTypeFloat>(h: &mut [F], n: usize) {
+    for k in 0..n.saturating_sub(2) {
+        let x: Vec<F> = (k + 1..n).map(|i| h[i * n + k]).collect();
+        if let Some((v, beta)) = house(&x) {
+            reflect_left(h, n, (&v, beta), k + 1, k..n);
+            reflect_right(h, n, (&v, beta), k + 1, 0..n);
+            for i in k + 2..n {
+                h[i * n + k] = F::zero();
+            }
+        }
+    }
+}
+
+/// Eigenvalues of the real `2 x 2` matrix `[[a, b], [c, d]]`, the one with
+/// the positive imaginary part first for a complex pair.
+fn eigvals_2x2<F: TensorTypeFloat>(a: F, b: F, c: F, d: F) -> [Complex<F>; 2] {
+    let half = (a - d) / (F::one() + F::one());
+    let disc = half * half + b * c;
+    let root = Float::sqrt(Float::abs(disc));
+
+    if disc < F::zero() {
+        return [Complex::new(d + half, root), Complex::new(d + half, -root)];
+    }
+
+    // The eigenvalues are d + half +- root. Adding root with the sign of half
+    // gives the larger shift without cancellation, and the product of both
+    // shifts, -b * c, gives the other one.
+    let big = match half < F::zero() {
+        true => half - root,
+        false => half + root,
+    };
+    let small = match big == F::zero() {
+        true => F::zero(),
+        false => -(b * c) / big,
+    };
+    [
+        Complex::new(d + big, F::zero()),
+        Complex::new(d + small, F::zero()),
+    ]
+}
+
+/// Francis double shift QR step on the unreduced block `lo..=hi` (at least
+/// `3 x 3`) of the row-major upper Hessenberg matrix `h` with `n` columns.
+/// The shifts are the roots of `z^2 - s * z + t`. Only the block is updated,
+/// which leaves its eigenvalues intact but not the rest of a Schur form.
+fn francis_step<F: TensorTypeFloat>(h: &mut [F], n: usize, lo: usize, hi: usize, (s, t): (F, F)) {
+    let at = |h: &[F], i: usize, j: usize| h[(lo + i) * n + lo + j];
+
+    // First column of (H - mu_1 * I) * (H - mu_2 * I) = H^2 - s * H + t * I,
+    // which only has three nonzeros
+    let (h00, h01, h10) = (at(h, 0, 0), at(h, 0, 1), at(h, 1, 0));
+    let (h11, h21) = (at(h, 1, 1), at(h, 2, 1));
+    let mut x = vec![
+        h00 * h00 + h01 * h10 - s * h00 + t,
+        h10 * (h00 + h11 - s),
+        h10 * h21,
+    ];
+
+    // Chase the bulge created by the first reflector down the subdiagonal
+    for k in lo..hi {
+        if let Some((v, beta)) = house(&x) {
+            let first = if k > lo { k - 1 } else { lo };
+            reflect_left(h, n, (&v, beta), k, first..hi + 1);
+            reflect_right(h, n, (&v, beta), k, lo..(k + 4).min(hi + 1));
+            if k > lo {
+                for i in k + 1..k + v.len() {
+                    h[i * n + k - 1] = F::zero();
+                }
+            }
+        }
+        x = (k + 1..(k + 4).min(hi + 1)).map(|i| h[i * n + k]).collect();
+    }
+}
+
+/// Eigenvalues of a row-major upper Hessenberg `n x n` matrix by the
+/// Francis double shift QR algorithm. Complex conjugate pairs are returned
+/// next to each other, the one with the positive imaginary part first.
+/// `None` if an eigenvalue doesn't converge.
+fn hessenberg_eigvals<F: TensorTypeFloat>(mut h: Vec<F>, n: usize) -> Option<Vec<Complex<F>>> {
+    let mut w = vec![Complex::new(F::zero(), F::zero()); n];
+    // Stands in for the neighbouring diagonal when deciding on deflation
+    let norm = h.iter().fold(F::zero(), |acc, &val| acc + Float::abs(val));
+
+    // The eigenvalues of rows and columns hi.. are already known
+    let mut hi = n;
+    let mut iters = 0;
+
+    while hi > 0 {
+        // Set negligible subdiagonal elements to zero, splitting off the
+        // unreduced block lo..hi at the bottom
+        let mut lo = hi - 1;
+        while lo > 0 {
+            let diag = Float::abs(h[lo * n + lo]) + Float::abs(h[(lo - 1) * n + lo - 1]);
+            let scale = if diag == F::zero() { norm } else { diag };
+            if Float::abs(h[lo * n + lo - 1]) <= F::epsilon() * scale {
+                h[lo * n + lo - 1] = F::zero();
+                break;
+            }
+            lo -= 1;
+        }
+
+        if hi - lo == 1 {
+            w[lo] = Complex::new(h[lo * n + lo], F::zero());
+            hi -= 1;
+            iters = 0;
+            continue;
+        }
+
+        // Trailing 2 x 2 block
+        let (a, b) = (h[(hi - 2) * n + hi - 2], h[(hi - 2) * n + hi - 1]);
+        let (c, d) = (h[(hi - 1) * n + hi - 2], h[(hi - 1) * n + hi - 1]);
+        match hi - lo {
+            2 => {
+                let [w0, w1] = eigvals_2x2(a, b, c, d);
+                (w[lo], w[lo + 1]) = (w0, w1);
+                hi -= 2;
+                iters = 0;
+            }
+            _ if iters == MAX_QR_ITERS => return None,
+            _ => {
+                iters += 1;
+                // The eigenvalues of the trailing 2 x 2 block are the usual
+                // shifts. When they stall, a double shift moved off the
+                // diagonal by the last subdiagonal elements breaks the cycle.
+                let shifts = match iters % EXCEPTIONAL_SHIFT_ITERS == 0 {
+                    true => {
+                        let e = Float::abs(c) + Float::abs(h[(hi - 2) * n + hi - 3]);
+                        let mu = d + e;
+                        (mu + mu, mu * mu)
+                    }
+                    false => (a + d, a * d - b * c),
+                };
+                francis_step(&mut h, n, lo, hi - 1, shifts);
+            }
+        }
+    }
+
+    Some(w)
+}
+
+/// LU factors with partial pivoting of `A - lambda * I` for a row-major
+/// `n x n` matrix `A`. Vanishing pivots are replaced by a tiny value so that
+/// solves blow up in the direction of the eigenvectors of `lambda`.
+struct ShiftedLu<F> {
+    lu: Vec<Complex<F>>,
+    perm: Vec<usize>,
+    n: usize,
+}
+
+impl<F: TensorTypeFloat> ShiftedLu<F> {
+    fn new(a: &[F], n: usize, lambda: Complex<F>, tiny: F) -> Self {
+        let mut lu: Vec<Complex<F>> = (0..n * n)
+            .map(|pos| match pos / n == pos % n {
+                true => Complex::new(a[pos], F::zero()) - lambda,
+                false => Complex::new(a[pos], F::zero()),
+            })
+            .collect();
+        let mut perm: Vec<usize> = (0..n).collect();
+
+        for k in 0..n {
+            let mut p = k;
+            for i in k + 1..n {
+                if lu[i * n + k].norm() > lu[p * n + k].norm() {
+                    p = i;
+                }
+            }
+            if p != k {
+                for j in 0..n {
+                    lu.swap(k * n + j, p * n + j);
+                }
+                perm.swap(k, p);
+            }
+            if lu[k * n + k].norm() < tiny {
+                lu[k * n + k] = Complex::new(tiny, F::zero());
+            }
+
+            let pivot = lu[k * n + k];
+            for i in k + 1..n {
+                let factor = lu[i * n + k] / pivot;
+                lu[i * n + k] = factor;
+                for j in k + 1..n {
+                    lu[i * n + j] = lu[i * n + j] - factor * lu[k * n + j];
+                }
+            }
+        }
+
+        ShiftedLu { lu, perm, n }
+    }
+
+    fn solve(&self, x: &[Complex<F>]) -> Vec<Complex<F>> {
+        let (lu, n) = (&self.lu, self.n);
+        let mut y: Vec<Complex<F>> = self.perm.iter().map(|&row| x[row]).collect();
+        for i in 0..n {
+            for j in 0..i {
+                y[i] = y[i] - lu[i * n + j] * y[j];
+            }
+        }
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                y[i] = y[i] - lu[i * n + j] * y[j];
+            }
+            y[i] = y[i] / lu[i * n + i];
+        }
+        y
+    }
+}
+
+/// `v^H x`.
+fn dot_conj<F: TensorTypeFloat>(v: &[Complex<F>], x: &[Complex<F>]) -> Complex<F> {
+    v.iter()
+        .zip(x)
+        .fold(Complex::new(F::zero(), F::zero()), |acc, (v, &x)| {
+            acc + v.conj() * x
+        })
+}
+
+/// Removes the components of `x` along the unit vectors of `basis`.
+fn orthogonalize<F: TensorTypeFloat>(x: &mut [Complex<F>], basis: &[&[Complex<F>]]) {
+    for v in basis {
+        let proj = dot_conj(v, x);
+        for (val, &v) in x.iter_mut().zip(v.iter()) {
+            *val = *val - proj * v;
+        }
+    }
+}
+
+fn unit<F: TensorTypeFloat>(x: Vec<Complex<F>>) -> Vec<Complex<F>> {
+    let norm = Float::sqrt(x.iter().fold(F::zero(), |acc, val| acc + val.norm_sqr()));
+    x.into_iter().map(|val| val.unscale(norm)).collect()
+}
+
+/// Unit vector from a few steps of inverse iteration on `start`, kept
+/// orthogonal to `basis` and scaled so that its largest component is real
+/// and positive. `None` if it vanishes. The step that grew the most is kept,
+/// since the iterates of a defective eigenvalue keep alternating with vectors
+/// outside its eigenspace.
+fn inverse_iteration<F: TensorTypeFloat>(
+    lu: &ShiftedLu<F>,
+    start: Vec<Complex<F>>,
+    basis: &[&[Complex<F>]],
+) -> Option<Vec<Complex<F>>> {
+    let zero = Complex::new(F::zero(), F::zero());
+    let mut x = start;
+    let mut best: Option<(F, Vec<Complex<F>>)> = None;
+
+    for _ in 0..3 {
+        let mut y = lu.solve(&x);
+        orthogonalize(&mut y, basis);
+
+        let largest = y
+            .iter()
+            .fold(zero, |acc, &val| match val.norm() > acc.norm() {
+                true => val,
+                false => acc,
+            });
+        if largest == zero {
+            return None;
+        }
+        x = y.iter().map(|&val| val / largest).collect();
+        if best
+            .as_ref()
+            .is_none_or(|(growth, _)| largest.norm() > *growth)
+        {
+            best = Some((largest.norm(), x.clone()));
+        }
+    }
+
+    best.map(|(_, x)| unit(x))
+}
+
+/// Unit eigenvectors of the row-major `n x n` matrix `a` for its
+/// eigenvalues `w`, by inverse iteration. An eigenvector repeating one
+/// already found for an equal eigenvalue is searched for again orthogonally
+/// to those, which finds the rest of the eigenspace unless the matrix is
+/// defective.
+fn eigvecs<F: TensorTypeFloat>(a: &[F], n: usize, w: &[Complex<F>]) -> Vec<Vec<Complex<F>>> {
+    let a_norm = a.iter().fold(F::zero(), |acc, &val| acc + Float::abs(val));
+    let tiny = match a_norm * F::epsilon() {
+        tiny if tiny > F::zero() => tiny,
+        _ => F::epsilon(),
+    };
+    let tol = Float::sqrt(F::epsilon());
+
+    let ones = unit(vec![Complex::new(F::one(), F::zero()); n]);
+    let residual = |lambda: Complex<F>, x: &[Complex<F>]| {
+        (0..n).fold(F::zero(), |acc, i| {
+            let ax = (0..n).fold(Complex::new(F::zero(), F::zero()), |acc, j| {
+                acc + x[j].scale(a[i * n + j])
+            });
+            Float::max(acc, (ax - lambda * x[i]).norm())
+        })
+    };
+
+    let mut vecs: Vec<Vec<Complex<F>>> = Vec::with_capacity(n);
+    for (i, &lambda) in w.iter().enumerate() {
+        let lu = ShiftedLu::new(a, n, lambda, tiny);
+        let mut x = inverse_iteration(&lu, ones.clone(), &[]).unwrap_or_else(|| ones.clone());
+
+        let equal: Vec<&[Complex<F>]> = w[..i]
+            .iter()
+            .zip(&vecs)
+            .filter(|(&mu, _)| (mu - lambda).norm() <= tol * a_norm)
+            .map(|(_, v)| v.as_slice())
+            .collect();
+        if equal
+            .iter()
+            .any(|v| dot_conj(v, &x).norm() > F::one() - tol)
+        {
+            // Start from the vectors of the standard basis and all ones,
+            // furthest from the eigenvectors found so far first, until one
+            // leads to another eigenvector
+            let mut starts: Vec<(F, Vec<Complex<F>>)> = (0..n)
+                .map(|k| {
+                    let mut e = vec![Complex::new(F::zero(), F::zero()); n];
+                    e[k] = Complex::new(F::one(), F::zero());
+                    e
+                })
+                .chain([ones.clone()])
+                .map(|mut e| {
+                    orthogonalize(&mut e, &equal);
+                    (Float::sqrt(dot_conj(&e, &e).re), e)
+                })
+                .filter(|(norm, _)| *norm > tol)
+                .collect();
+            starts.sort_by(|e, f| f.0.partial_cmp(&e.0).unwrap_or(Ordering::Equal));
+
+            let found = starts.into_iter().find_map(|(_, start)| {
+                inverse_iteration(&lu, start, &equal)
+                    .filter(|y| residual(lambda, y) <= tol * a_norm)
+            });
+            if let Some(y) = found {
+                x = y;
+            }
+        }
+        vecs.push(x);
+    }
+
+    vecs
+}
+
+impl<'a, F, S> TensorBase<'a, F, S>
+where
+    F: TensorTypeFloat,
+    S: TensorStorage<F>,
+{
+    /// Eigenvalues in ascending order and eigenvectors of a (batch of) real
+    /// symmetric matrices, by cyclic Jacobi rotations. Only the lower
+    /// triangle is read. Eigenvector `i` is column `i` of the second tensor.
+    pub fn eigh(&self) -> Result<(Tensor<'static, F>, Tensor<'static, F>), Error> {
+        let (batch, n) = self.square_batch()?;
+        let data = self.data();
+        let nbatch: usize = batch.iter().product();
+        let mut data_w = Vec::with_capacity(nbatch * n);
+        let mut data_v = Vec::with_capacity(nbatch * n * n);
+
+        for b in 0..nbatch {
+            let (w, v) = jacobi_eigh(&data[b * n * n..(b + 1) * n * n], n).ok_or_else(|| {
+                Error::NoConvergence {
+                    index: unravel(b, &batch),
+                }
+            })?;
+            data_w.extend(w);
+            data_v.extend(v);
+        }
+
+        let mut shape_w = batch;
+        shape_w.push(n);
+
+        Ok((
+            Tensor::from_shape(&shape_w, data_w)?,
+            Tensor::from_shape(&self.shape, data_v)?,
+        ))
+    }
+
+    fn svd_batch(&self, full_matrices: bool) -> Result<(Vec<usize>, Vec<SvdFactors<F>>), Error> {
+        let (batch, m, n) = self.matrix_batch()?;
+        let data = self.data();
+        let factors = (0..batch.iter().product())
+            .map(|b| {
+                svd_matrix(&data[b * m * n..(b + 1) * m * n], m, n, full_matrices).ok_or_else(
+                    || Error::NoConvergence {
+                        index: unravel(b, &batch),
+                    },
+                )
+            })
+            .collect::<Result<_, _>>()?;
+        Ok((batch, factors))
+    }
+
+    /// Singular value decomposition of a (batch of) `m x n` matrices,
+    /// `A = U * diag(S) * Vt`, with the singular values in descending order.
+    /// With `full_matrices` `U` is `m x m` and `Vt` is `n x n`, otherwise
+    /// they are `m x k` and `k x n` with `k = min(m, n)`.
+    #[allow(clippy::type_complexity)]
+    pub fn svd(
+        &self,
+        full_matrices: bool,
+    ) -> Result<(Tensor<'static, F>, Tensor<'static, F>, Tensor<'static, F>), Error> {
+        let (_, m, n) = self.matrix_batch()?;
+        let (batch, factors) = self.svd_batch(full_matrices)?;
+        let (cols_u, rows_vt) = match full_matrices {
+            true => (m, n),
+            false => (m.min(n), m.min(n)),
+        };
+
+        let mut shape_u = batch.clone();
+        shape_u.extend_from_slice(&[m, cols_u]);
+        let mut shape_s = batch.clone();
+        shape_s.push(m.min(n));
+        let mut shape_vt = batch;
+        shape_vt.extend_from_slice(&[rows_vt, n]);
+
+        Ok((
+            Tensor::from_shape(&shape_u, factors.iter().flat_map(|f| f.u.clone()).collect())?,
+            Tensor::from_shape(&shape_s, factors.iter().flat_map(|f| f.s.clone()).collect())?,
+            Tensor::from_shape(
+                &shape_vt,
+                factors.iter().flat_map(|f| f.vt.clone()).collect(),
+            )?,
+        ))
+    }
+
+    /// Moore-Penrose pseudo-inverse of a (batch of) `m x n` matrices, with
+    /// shape `[..., n, m]`.
+    pub fn pinv(&self) -> Result<Tensor<'static, F>, Error> {
+        let (_, m, n) = self.matrix_batch()?;
+        let (batch, factors) = self.svd_batch(false)?;
+        let k = m.min(n);
+        let mut data = Vec::with_capacity(factors.len() * n * m);
+
+        for factor in &factors {
+            let tol = rank_tol(&factor.s, m, n);
+            for i in 0..n {
+                for j in 0..m {
+                    let mut val = F::zero();
+                    for (l, &sigma) in factor.s.iter().enumerate() {
+                        if sigma > tol {
+                            val += factor.vt[l * n + i] * factor.u[j * k + l] / sigma;
+                        }
+                    }
+                    data.push(val);
+                }
+            }
+        }
+
+        let mut shape = batch;
+        shape.extend_from_slice(&[n, m]);
+        Tensor::from_shape(&shape, data)
+    }
+
+    /// Reduces the singular values of every matrix in the batch with `f`.
+    fn reduce_singular<V, G>(&self, mut f: G) -> Result<Tensor<'static, V>, Error>
+    where
+        V: TensorType,
+        G: FnMut(&[F]) -> V,
+    {
+        let (batch, factors) = self.svd_batch(false)?;
+        Tensor::from_shape(&batch, factors.iter().map(|factor| f(&factor.s)).collect())
+    }
+
+    /// Number of singular values above `max(m, n) * eps * max(S)` for every
+    /// matrix in the batch.
+    pub fn matrix_rank(&self) -> Result<Tensor<'static, usize>, Error> {
+        let (_, m, n) = self.matrix_batch()?;
+        self.reduce_singular(|s| {
+            let tol = rank_tol(s, m, n);
+            s.iter().filter(|&&sigma| sigma > tol).count()
+        })
+    }
+
+    /// Condition number in the 2-norm, the ratio of the largest to the
+    /// smallest singular value. Singular matrices give infinity.
+    pub fn cond(&self) -> Result<Tensor<'static, F>, Error> {
+        self.reduce_singular(|s| match (s.first(), s.last()) {
+            (Some(&max), Some(&min)) => max / min,
+            _ => F::zero(),
+        })
+    }
+
+    /// Nuclear norm, the sum of the singular values.
+    pub fn norm_nuc(&self) -> Result<Tensor<'static, F>, Error> {
+        self.reduce_singular(|s| s.iter().fold(F::zero(), |acc, &sigma| acc + sigma))
+    }
+
+    /// Spectral norm, the largest singular value.
+    pub fn norm_spectral(&self) -> Result<Tensor<'static, F>, Error> {
+        self.reduce_singular(|s| s.first().copied().unwrap_or_else(F::zero))
+    }
+}
+
+impl<'a, F, S> TensorBase<'a, F, S>
+where
+    F: TensorTypeFloat,
+    Complex<F>: TensorType,
+    S: TensorStorage<F>,
+{
+    /// Eigenvalues and right eigenvectors of a (batch of) general square
+    /// matrices. The eigenvalues come from the Francis double shift QR
+    /// algorithm on the Hessenberg form, with complex conjugate pairs next to
+    /// each other. Eigenvector `i` is column `i` of the second tensor, found
+    /// by inverse iteration and normalized to unit length. Repeated
+    /// eigenvalues get independent eigenvectors spanning their eigenspace,
+    /// except for defective matrices, which lack them and give repeated
+    /// eigenvectors instead.
+    #[allow(clippy::type_complexity)]
+    pub fn eig(&self) -> Result<(Tensor<'static, Complex<F>>, Tensor<'static, Complex<F>>), Error> {
+        let (batch, n) = self.square_batch()?;
+        let data = self.data();
+        let nbatch: usize = batch.iter().product();
+        let mut data_w = Vec::with_capacity(nbatch * n);
+        let mut data_v = vec![Complex::new(F::zero(), F::zero()); nbatch * n * n];
+
+        for b in 0..nbatch {
+            let a = &data[b * n * n..(b + 1) * n * n];
+            let no_convergence = || Error::NoConvergence {
+                index: unravel(b, &batch),
+            };
+            if a.iter().any(|val| !val.is_finite()) {
+                return Err(no_convergence());
+            }
+
+            let mut h = a.to_vec();
+            hessenberg(&mut h, n);
+            let w = hessenberg_eigvals(h, n).ok_or_else(no_convergence)?;
+
+            for (col, v) in eigvecs(a, n, &w).into_iter().enumerate() {
+                for (row, val) in v.into_iter().enumerate() {
+                    data_v[b * n * n + row * n + col] = val;
+                }
+            }
+            data_w.extend(w);
+        }
+
+        let mut shape_w = batch;
+        shape_w.push(n);
+
+        Ok((
+            Tensor::from_shape(&shape_w, data_w)?,
+            Tensor::from_shape(&self.shape, data_v)?,
+        ))
+    }
+}
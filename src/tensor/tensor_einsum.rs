@@ -0,0 +1,363 @@
+use super::*;
+
+/// Label ids `0..LETTERS` are the letters `A-Z` and `a-z` of a subscript, the
+/// ids from `LETTERS` up label the broadcast dimensions of an ellipsis.
+const LETTERS: usize = 52;
+
+/// One operand of a contraction: the storage, offset, shape and strides of
+/// a tensor together with the label of each of its axes.
+struct Operand<'b, U> {
+    data: &'b [U],
+    offset: usize,
+    shape: &'b [usize],
+    strides: &'b [usize],
+    labels: Vec<usize>,
+}
+
+/// Sums the product of the operands over every label that isn't in `out`.
+/// Axes sharing a label within an operand walk its diagonal, axes of size 1
+/// broadcast against the other operands and labels of `out` that no operand
+/// has get size 1.
+fn contract<U: TensorTypeNumeric>(
+    operands: &[Operand<'_, U>],
+    out: &[usize],
+) -> Result<Tensor<'static, U>, Error> {
+    let nlabels = operands
+        .iter()
+        .flat_map(|op| op.labels.iter())
+        .chain(out)
+        .max()
+        .map_or(0, |&label| label + 1);
+
+    // Size and stride of every label within each operand
+    let mut sizes = vec![1; nlabels];
+    let mut owner: Vec<Option<usize>> = vec![None; nlabels];
+    let mut op_strides = Vec::with_capacity(operands.len());
+    for (o, op) in operands.iter().enumerate() {
+        let mut op_sizes: Vec<Option<usize>> = vec![None; nlabels];
+        let mut strides = vec![0; nlabels];
+        for (axis, &label) in op.labels.iter().enumerate() {
+            let dim = op.shape[axis];
+            match op_sizes[label] {
+                Some(size) if size != dim => {
+                    return Err(Error::InvalidParam {
+                        err_msg: format!("repeated label with dimensions {} and {}", size, dim),
+                    })
+                }
+                _ => op_sizes[label] = Some(dim),
+            }
+            strides[label] += op.strides.get(axis).copied().unwrap_or(0);
+        }
+
+        for label in 0..nlabels {
+            match op_sizes[label] {
+                Some(1) => strides[label] = 0,
+                Some(dim) => match owner[label] {
+                    Some(prev) if sizes[label] != dim => {
+                        return Err(Error::ShapeMismatch {
+                            shape_a: operands[prev].shape.to_vec(),
+                            shape_b: op.shape.to_vec(),
+                        })
+                    }
+                    _ => {
+                        sizes[label] = dim;
+                        owner[label] = Some(o);
+                    }
+                },
+                None => {}
+            }
+        }
+        op_strides.push(strides);
+    }
+
+    // Output labels vary slowest, summed labels fastest
+    let summed = (0..nlabels).filter(|label| {
+        !out.contains(label) && operands.iter().any(|op| op.labels.contains(label))
+    });
+    let order: Vec<usize> = out.iter().copied().chain(summed).collect();
+    let dims: Vec<usize> = order.iter().map(|&label| sizes[label]).collect();
+    let strides: Vec<Vec<usize>> = op_strides
+        .iter()
+        .map(|op| order.iter().map(|&label| op[label]).collect())
+        .collect();
+
+    let shape = &dims[..out.len()];
+    let len_out: usize = shape.iter().product();
+    let len_sum: usize = dims[out.len()..].iter().product();
+    let mut data = vec![U::default(); len_out];
+
+    let mut index = vec![0; order.len()];
+    let mut offsets: Vec<usize> = operands.iter().map(|op| op.offset).collect();
+    for pos in 0..len_out * len_sum {
+        let mut prod = U::one();
+        for (op, &offset) in operands.iter().zip(&offsets) {
+            prod *= op.data[offset];
+        }
+        data[pos / len_sum] += prod;
+
+        for d in (0..order.len()).rev() {
+            index[d] += 1;
+            for (offset, op) in offsets.iter_mut().zip(&strides) {
+                *offset += op[d];
+            }
+            if index[d] < dims[d] {
+                break;
+            }
+            for (offset, op) in offsets.iter_mut().zip(&strides) {
+                *offset -= op[d] * dims[d];
+            }
+            index[d] = 0;
+        }
+    }
+
+    Tensor::from_shape(shape, data)
+}
+
+/// Label ids of a subscript term, with `None` standing for an ellipsis.
+fn parse_term(term: &str) -> Result<Vec<Option<usize>>, Error> {
+    let mut labels = Vec::new();
+    let mut chars = term.chars().filter(|c| !c.is_whitespace());
+
+    while let Some(c) = chars.next() {
+        match c {
+            'A'..='Z' => labels.push(Some(c as usize - 'A' as usize)),
+            'a'..='z' => labels.push(Some(c as usize - 'a' as usize + 26)),
+            '.' if chars.next() == Some('.') && chars.next() == Some('.') => {
+                if labels.contains(&None) {
+                    return Err(Error::InvalidParam {
+                        err_msg: format!("more than one ellipsis in '{}'", term),
+                    });
+                }
+                labels.push(None);
+            }
+            _ => {
+                return Err(Error::InvalidParam {
+                    err_msg: format!("invalid subscript '{}'", term),
+                })
+            }
+        }
+    }
+    Ok(labels)
+}
+
+/// Replaces the ellipsis of a term by the labels of the last `ndim` of the
+/// `ell_ndim` broadcast dimensions.
+fn expand_term(term: &[Option<usize>], ndim: usize, ell_ndim: usize) -> Vec<usize> {
+    term.iter()
+        .flat_map(|label| match label {
+            Some(label) => vec![*label],
+            None => (LETTERS + ell_ndim - ndim..LETTERS + ell_ndim).collect(),
+        })
+        .collect()
+}
+
+impl<'a, U> Tensor<'a, U>
+where
+    U: TensorTypeNumeric,
+{
+    /// Einstein summation over `operands` as described by `subscripts`, like
+    /// NumPy's `einsum`, e.g. `"bij,bjk->bik"` for a batched matrix product.
+    /// Labels repeated within an operand take its diagonal and labels missing
+    /// from the output are summed over. `...` stands for broadcast leading
+    /// dimensions. Without `->` the output has the broadcast dimensions
+    /// followed by the labels appearing once, in alphabetical order.
+    pub fn einsum(
+        subscripts: &str,
+        operands: &[TensorView<'_, U>],
+    ) -> Result<Tensor<'static, U>, Error> {
+        let (inputs, output) = match subscripts.split_once("->") {
+            Some((inputs, output)) => (inputs, Some(parse_term(output)?)),
+            None => (subscripts, None),
+        };
+        let terms = inputs
+            .split(',')
+            .map(parse_term)
+            .collect::<Result<Vec<_>, Error>>()?;
+        if terms.len() != operands.len() {
+            return Err(Error::InvalidParam {
+                err_msg: format!(
+                    "{} subscript terms for {} operands",
+                    terms.len(),
+                    operands.len()
+                ),
+            });
+        }
+
+        // Number of dimensions covered by the ellipsis of each operand
+        let mut ell_ndims = Vec::with_capacity(operands.len());
+        for (term, op) in terms.iter().zip(operands) {
+            let nletters = term.iter().filter(|label| label.is_some()).count();
+            let ndim = op.shape.len();
+            match term.contains(&None) {
+                true if ndim >= nletters => ell_ndims.push(ndim - nletters),
+                false if ndim == nletters => ell_ndims.push(0),
+                _ => {
+                    return Err(Error::DimensionMismatch {
+                        tensor_dim: ndim,
+                        dim: nletters,
+                    })
+                }
+            }
+        }
+        let ell_ndim = ell_ndims.iter().copied().max().unwrap_or(0);
+
+        let labels: Vec<Vec<usize>> = terms
+            .iter()
+            .zip(&ell_ndims)
+            .map(|(term, &ndim)| expand_term(term, ndim, ell_ndim))
+            .collect();
+        let count = |label: usize| labels.iter().flatten().filter(|&&l| l == label).count();
+
+        let out = match output {
+            Some(term) => {
+                let out = expand_term(&term, ell_ndim, ell_ndim);
+                for (i, &label) in out.iter().enumerate() {
+                    if label < LETTERS && (count(label) == 0 || out[..i].contains(&label)) {
+                        return Err(Error::InvalidParam {
+                            err_msg: format!("invalid output subscript in '{}'", subscripts),
+                        });
+                    }
+                }
+                if ell_ndim > 0 && !term.contains(&None) {
+                    return Err(Error::InvalidParam {
+                        err_msg: format!("output of '{}' lacks the ellipsis", subscripts),
+                    });
+                }
+                out
+            }
+            None => (LETTERS..LETTERS + ell_ndim)
+                .chain((0..LETTERS).filter(|&label| count(label) == 1))
+                .collect(),
+        };
+
+        let operands: Vec<Operand<'_, U>> = operands
+            .iter()
+            .zip(labels)
+            .map(|(op, labels)| op.operand(labels))
+            .collect();
+        contract(&operands, &out)
+    }
+}
+
+impl<'a, U, S> TensorBase<'a, U, S>
+where
+    U: TensorTypeNumeric,
+    S: TensorStorage<U>,
+{
+    fn operand(&self, labels: Vec<usize>) -> Operand<'_, U> {
+        Operand {
+            data: self.data.as_ref(),
+            offset: self.offset,
+            shape: &self.shape,
+            strides: &self.strides,
+            labels,
+        }
+    }
+
+    /// Sums the product of `self` and `tensor_b` over the pairs of axes
+    /// `axes_a` and `axes_b`. The result has the remaining axes of `self`
+    /// followed by the remaining axes of `tensor_b`.
+    pub fn tensordot(
+        &self,
+        tensor_b: &TensorView<'_, U>,
+        axes_a: &[usize],
+        axes_b: &[usize],
+    ) -> Result<Tensor<'static, U>, Error> {
+        if axes_a.len() != axes_b.len() {
+            return Err(Error::InvalidParam {
+                err_msg: format!(
+                    "{} axes of the first tensor paired with {} axes of the second",
+                    axes_a.len(),
+                    axes_b.len()
+                ),
+            });
+        }
+        let (ndim_a, ndim_b) = (self.shape.len(), tensor_b.shape.len());
+        for (axes, ndim) in [(axes_a, ndim_a), (axes_b, ndim_b)] {
+            for (i, &axis) in axes.iter().enumerate() {
+                if axis >= ndim {
+                    return Err(Error::InvalidAxis { axis, ndim });
+                }
+                if axes[..i].contains(&axis) {
+                    return Err(Error::InvalidParam {
+                        err_msg: format!("repeated axis {}", axis),
+                    });
+                }
+            }
+        }
+        for (&axis_a, &axis_b) in axes_a.iter().zip(axes_b) {
+            if self.shape[axis_a] != tensor_b.shape[axis_b] {
+                return Err(Error::ShapeMismatch {
+                    shape_a: self.shape(),
+                    shape_b: tensor_b.shape(),
+                });
+            }
+        }
+
+        // The axes of `tensor_b` are labelled after those of `self`, except
+        // for the contracted ones which take the label of their pair
+        let labels_a: Vec<usize> = (0..ndim_a).collect();
+        let labels_b: Vec<usize> = (0..ndim_b)
+            .map(|axis| match axes_b.iter().position(|&a| a == axis) {
+                Some(i) => axes_a[i],
+                None => ndim_a + axis,
+            })
+            .collect();
+        let out: Vec<usize> = (0..ndim_a)
+            .filter(|axis| !axes_a.contains(axis))
+            .chain(
+                (0..ndim_b)
+                    .filter(|axis| !axes_b.contains(axis))
+                    .map(|axis| ndim_a + axis),
+            )
+            .collect();
+
+        contract(&[self.operand(labels_a), tensor_b.operand(labels_b)], &out)
+    }
+
+    /// Dot product following NumPy: the matrix product for 2-D tensors, the
+    /// sum product over the last axis of `self` and the second to last axis
+    /// of `tensor_b` in general, and the product when either is 0-d.
+    pub fn dot(&self, tensor_b: &TensorView<'_, U>) -> Result<Tensor<'static, U>, Error> {
+        let (ndim_a, ndim_b) = (self.shape.len(), tensor_b.shape.len());
+        match (ndim_a, ndim_b) {
+            (0, _) | (_, 0) => self.tensordot(tensor_b, &[], &[]),
+            (_, 1) => self.tensordot(tensor_b, &[ndim_a - 1], &[0]),
+            _ => self.tensordot(tensor_b, &[ndim_a - 1], &[ndim_b - 2]),
+        }
+    }
+
+    /// Sum product over the last axes of `self` and `tensor_b`, or the
+    /// product when either is 0-d.
+    pub fn inner(&self, tensor_b: &TensorView<'_, U>) -> Result<Tensor<'static, U>, Error> {
+        let (ndim_a, ndim_b) = (self.shape.len(), tensor_b.shape.len());
+        match ndim_a == 0 || ndim_b == 0 {
+            true => self.tensordot(tensor_b, &[], &[]),
+            false => self.tensordot(tensor_b, &[ndim_a - 1], &[ndim_b - 1]),
+        }
+    }
+
+    /// Outer product of the flattened `self` and `tensor_b`, with shape
+    /// `[self.nelems(), tensor_b.nelems()]`.
+    pub fn outer(&self, tensor_b: &TensorView<'_, U>) -> Result<Tensor<'static, U>, Error> {
+        let prod = self.tensordot(tensor_b, &[], &[])?;
+        Tensor::from_shape(&[self.nelems(), tensor_b.nelems()], prod.data)
+    }
+
+    /// Kronecker product. The tensor with fewer dimensions is padded with
+    /// leading axes of size 1 and the result has the dimensions
+    /// `self.shape[i] * tensor_b.shape[i]`.
+    pub fn kron(&self, tensor_b: &TensorView<'_, U>) -> Result<Tensor<'static, U>, Error> {
+        let (ndim_a, ndim_b) = (self.shape.len(), tensor_b.shape.len());
+        let ndim = ndim_a.max(ndim_b);
+
+        // Interleave the axes as `a_0, b_0, a_1, b_1, ...` and merge each pair
+        let labels_a: Vec<usize> = (ndim - ndim_a..ndim).map(|axis| 2 * axis).collect();
+        let labels_b: Vec<usize> = (ndim - ndim_b..ndim).map(|axis| 2 * axis + 1).collect();
+        let out: Vec<usize> = (0..2 * ndim).collect();
+        let prod = contract(&[self.operand(labels_a), tensor_b.operand(labels_b)], &out)?;
+
+        let shape: Vec<usize> = prod.shape.chunks(2).map(|pair| pair[0] * pair[1]).collect();
+        Tensor::from_shape(&shape, prod.data)
+    }
+}
@@ -1,8 +1,14 @@
 use super::*;
 
+/// Tensors are equal if they have the same shape and elements, regardless of
+/// how the elements are laid out in their buffers.
+fn view_eq<U: TensorType>(view_a: &TensorView<U>, view_b: &TensorView<U>) -> bool {
+    view_a.shape == view_b.shape && view_a.iter().eq(view_b.iter())
+}
+
 impl<U> TensorEq<U> for U
 where
-    U: TensorTypeNumeric,
+    U: TensorType,
 {
     fn tensor_eq(&self, view: &TensorView<U>, indices: &mut Vec<usize>, _depth: usize) -> bool {
         if view.getval(indices).unwrap() == *self {
@@ -15,7 +21,7 @@ where
 
 impl<U, T> TensorEq<U> for Vec<T>
 where
-    U: TensorTypeNumeric,
+    U: TensorType,
     T: TensorEq<U> + Debug,
 {
     fn tensor_eq(&self, view: &TensorView<U>, indices: &mut Vec<usize>, depth: usize) -> bool {
@@ -33,7 +39,7 @@ where
 
 impl<'a, U, T> TensorEq<U> for &'a Vec<T>
 where
-    U: TensorTypeNumeric,
+    U: TensorType,
     T: TensorEq<U> + Debug,
 {
     fn tensor_eq(&self, view: &TensorView<U>, indices: &mut Vec<usize>, depth: usize) -> bool {
@@ -51,72 +57,72 @@ where
 
 impl<'a, U> TensorEq<U> for Tensor<'a, U>
 where
-    U: TensorTypeNumeric,
+    U: TensorType,
 {
     fn tensor_eq(&self, view: &TensorView<U>, _indices: &mut Vec<usize>, _depth: usize) -> bool {
-        self.data == view.data && self.shape == view.shape && self.strides == view.strides
+        view_eq(&self.view(), view)
     }
 }
 
 impl<'a, U> TensorEq<U> for &Tensor<'a, U>
 where
-    U: TensorTypeNumeric,
+    U: TensorType,
 {
     fn tensor_eq(&self, view: &TensorView<U>, _indices: &mut Vec<usize>, _depth: usize) -> bool {
-        self.data == view.data && self.shape == view.shape && self.strides == view.strides
+        view_eq(&self.view(), view)
     }
 }
 
 impl<'a, U> TensorEq<U> for TensorView<'a, U>
 where
-    U: TensorTypeNumeric,
+    U: TensorType,
 {
     fn tensor_eq(&self, view: &TensorView<U>, _indices: &mut Vec<usize>, _depth: usize) -> bool {
-        self.data == view.data && self.shape == view.shape && self.strides == view.strides
+        view_eq(&self.view(), view)
     }
 }
 
 impl<'a, U> TensorEq<U> for &TensorView<'a, U>
 where
-    U: TensorTypeNumeric,
+    U: TensorType,
 {
     fn tensor_eq(&self, view: &TensorView<U>, _indices: &mut Vec<usize>, _depth: usize) -> bool {
-        self.data == view.data && self.shape == view.shape && self.strides == view.strides
+        view_eq(&self.view(), view)
     }
 }
 
 impl<'a, U> TensorEq<U> for TensorViewMut<'a, U>
 where
-    U: TensorTypeNumeric,
+    U: TensorType,
 {
     fn tensor_eq(&self, view: &TensorView<U>, _indices: &mut Vec<usize>, _depth: usize) -> bool {
-        self.data == view.data && self.shape == view.shape && self.strides == view.strides
+        view_eq(&self.view(), view)
     }
 }
 
 impl<'a, U> TensorEq<U> for &TensorViewMut<'a, U>
 where
-    U: TensorTypeNumeric,
+    U: TensorType,
 {
     fn tensor_eq(&self, view: &TensorView<U>, _indices: &mut Vec<usize>, _depth: usize) -> bool {
-        self.data == view.data && self.shape == view.shape && self.strides == view.strides
+        view_eq(&self.view(), view)
     }
 }
 
 impl<'a, U, T> PartialEq<T> for Tensor<'a, U>
 where
-    U: TensorTypeNumeric,
+    U: TensorType,
     T: TensorEq<U> + Debug,
 {
     fn eq(&self, other: &T) -> bool {
         let mut indices = Vec::with_capacity(self.ndim());
-        other.tensor_eq(&self.axis(0).unwrap(), &mut indices, 0)
+        other.tensor_eq(&self.view(), &mut indices, 0)
     }
 }
 
 impl<'a, U, T> PartialEq<T> for TensorView<'a, U>
 where
-    U: TensorTypeNumeric,
+    U: TensorType,
     T: TensorEq<U> + Debug,
 {
     fn eq(&self, other: &T) -> bool {
@@ -127,11 +133,22 @@ where
 
 impl<'a, U, T> PartialEq<T> for TensorViewMut<'a, U>
 where
-    U: TensorTypeNumeric,
+    U: TensorType,
+    T: TensorEq<U> + Debug,
+{
+    fn eq(&self, other: &T) -> bool {
+        let mut indices = Vec::with_capacity(self.ndim());
+        other.tensor_eq(&self.view(), &mut indices, 0)
+    }
+}
+
+impl<'a, U, T> PartialEq<T> for TensorCow<'a, U>
+where
+    U: TensorType,
     T: TensorEq<U> + Debug,
 {
     fn eq(&self, other: &T) -> bool {
         let mut indices = Vec::with_capacity(self.ndim());
-        other.tensor_eq(&self.axis(0).unwrap(), &mut indices, 0)
+        other.tensor_eq(&self.view(), &mut indices, 0)
     }
 }
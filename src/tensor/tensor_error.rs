@@ -54,6 +54,66 @@ impl Display for Error {
                     err_msg
                 }
             }
+            Error::DivisionByZero { index } => {
+                write! {
+                    f,
+                    "Division by zero [ INDEX: {:?} ]",
+                    index
+                }
+            }
+            Error::Overflow { index } => {
+                write! {
+                    f,
+                    "Integer overflow [ INDEX: {:?} ]",
+                    index
+                }
+            }
+            Error::SingularMatrix { index } => {
+                write! {
+                    f,
+                    "Singular matrix [ BATCH INDEX: {:?} ]",
+                    index
+                }
+            }
+            Error::NotPositiveDefinite { index } => {
+                write! {
+                    f,
+                    "Matrix is not positive definite [ BATCH INDEX: {:?} ]",
+                    index
+                }
+            }
+            Error::NoConvergence { index } => {
+                write! {
+                    f,
+                    "Iterative algorithm did not converge [ BATCH INDEX: {:?} ]",
+                    index
+                }
+            }
+            Error::RaggedData {
+                index,
+                len,
+                expected,
+            } => {
+                write! {
+                    f,
+                    "Ragged nested data [ INDEX: {:?} | LENGTH: {} | EXPECTED: {} ]",
+                    index, len, expected
+                }
+            }
+            Error::DataLengthMismatch { shape, len } => {
+                write! {
+                    f,
+                    "Data length does not match the shape [ SHAPE: {:?} | LENGTH: {} ]",
+                    shape, len
+                }
+            }
+            Error::InvalidCast { index, value } => {
+                write! {
+                    f,
+                    "Value not representable in the target type [ INDEX: {:?} | VALUE: {} ]",
+                    index, value
+                }
+            }
             Error::InvalidFileContents { err_msg } => {
                 write! {
                     f,
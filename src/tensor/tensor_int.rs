@@ -0,0 +1,105 @@
+use super::*;
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub};
+use num_traits::{SaturatingAdd, SaturatingMul, SaturatingSub};
+use num_traits::{WrappingAdd, WrappingMul, WrappingSub};
+use tensor_arithmetic::scalar_view;
+
+macro_rules! impl_int_op {
+    (
+        $checked:ident, $saturating:ident, $wrapping:ident,
+        $checked_scalar:ident, $saturating_scalar:ident, $wrapping_scalar:ident,
+        $checked_op:path, $saturating_op:path, $wrapping_op:path, $desc:literal
+    ) => {
+        impl<'a, U, S> TensorBase<'a, U, S>
+        where
+            U: TensorTypeInt,
+            S: TensorStorage<U>,
+        {
+            #[doc = concat!("Element-wise ", $desc, " with broadcasting. Fails with")]
+            /// `Error::Overflow` naming the first overflowing index of the
+            /// broadcast result.
+            pub fn $checked(
+                &self,
+                tensor_b: &TensorView<'_, U>,
+            ) -> Result<Tensor<'static, U>, Error> {
+                self.zip_bc(tensor_b, |index, a, b| {
+                    $checked_op(&a, &b).ok_or_else(|| Error::Overflow {
+                        index: index.to_vec(),
+                    })
+                })
+            }
+
+            #[doc = concat!("Element-wise ", $desc, " with broadcasting, clamped to the")]
+            /// bounds of the element type.
+            pub fn $saturating(
+                &self,
+                tensor_b: &TensorView<'_, U>,
+            ) -> Result<Tensor<'static, U>, Error> {
+                self.zip_bc(tensor_b, |_, a, b| Ok($saturating_op(&a, &b)))
+            }
+
+            #[doc = concat!("Element-wise ", $desc, " with broadcasting, wrapping around")]
+            /// at the bounds of the element type.
+            pub fn $wrapping(
+                &self,
+                tensor_b: &TensorView<'_, U>,
+            ) -> Result<Tensor<'static, U>, Error> {
+                self.zip_bc(tensor_b, |_, a, b| Ok($wrapping_op(&a, &b)))
+            }
+
+            #[doc = concat!("Scalar ", $desc, ", see [`Self::", stringify!($checked), "`].")]
+            pub fn $checked_scalar(&self, scalar: U) -> Result<Tensor<'static, U>, Error> {
+                self.$checked(&scalar_view(&scalar))
+            }
+
+            #[doc = concat!("Scalar ", $desc, ", see [`Self::", stringify!($saturating), "`].")]
+            pub fn $saturating_scalar(&self, scalar: U) -> Tensor<'static, U> {
+                // A 0-d operand always broadcasts
+                self.$saturating(&scalar_view(&scalar)).unwrap()
+            }
+
+            #[doc = concat!("Scalar ", $desc, ", see [`Self::", stringify!($wrapping), "`].")]
+            pub fn $wrapping_scalar(&self, scalar: U) -> Tensor<'static, U> {
+                // A 0-d operand always broadcasts
+                self.$wrapping(&scalar_view(&scalar)).unwrap()
+            }
+        }
+    };
+}
+
+impl_int_op!(
+    checked_add,
+    saturating_add,
+    wrapping_add,
+    checked_add_scalar,
+    saturating_add_scalar,
+    wrapping_add_scalar,
+    CheckedAdd::checked_add,
+    SaturatingAdd::saturating_add,
+    WrappingAdd::wrapping_add,
+    "addition"
+);
+impl_int_op!(
+    checked_sub,
+    saturating_sub,
+    wrapping_sub,
+    checked_sub_scalar,
+    saturating_sub_scalar,
+    wrapping_sub_scalar,
+    CheckedSub::checked_sub,
+    SaturatingSub::saturating_sub,
+    WrappingSub::wrapping_sub,
+    "subtraction"
+);
+impl_int_op!(
+    checked_mul,
+    saturating_mul,
+    wrapping_mul,
+    checked_mul_scalar,
+    saturating_mul_scalar,
+    wrapping_mul_scalar,
+    CheckedMul::checked_mul,
+    SaturatingMul::saturating_mul,
+    WrappingMul::wrapping_mul,
+    "multiplication"
+);
@@ -1,11 +1,9 @@
-use super::{
-    TensorBase, TensorIter, TensorIterMut, TensorStorage, TensorStorageMut, TensorTypeNumeric,
-};
+use super::{TensorBase, TensorIter, TensorIterMut, TensorStorage, TensorStorageMut, TensorType};
 use std::marker::PhantomData;
 
 impl<'a, U> Iterator for TensorIter<'a, U>
 where
-    U: TensorTypeNumeric,
+    U: TensorType,
 {
     type Item = U;
 
@@ -38,7 +36,7 @@ where
 
 impl<'a, U> Iterator for TensorIterMut<'a, U>
 where
-    U: TensorTypeNumeric,
+    U: TensorType,
 {
     type Item = &'a mut U;
 
@@ -72,16 +70,23 @@ where
 
 impl<'a, U, S> TensorBase<'a, U, S>
 where
-    U: TensorTypeNumeric,
+    U: TensorType,
     S: TensorStorage<U>,
 {
     pub fn iter(&'a self) -> TensorIter<'a, U> {
+        // A 0-d tensor is iterated as a single element, an empty one not at all
+        let (shape, strides): (&[usize], &[usize]) = match self.ndim() {
+            _ if self.shape.contains(&0) => (&[0], &[0]),
+            0 => (&[1], &[0]),
+            _ => (&self.shape, &self.strides),
+        };
+
         TensorIter {
-            shape: &self.shape,
-            strides: &self.strides,
+            shape,
+            strides,
             offset: &self.offset,
             data: &self.data.as_ref(),
-            index: vec![0; self.ndim()],
+            index: vec![0; shape.len()],
             _u: PhantomData,
         }
     }
@@ -89,18 +94,23 @@ where
 
 impl<'a, U, S> TensorBase<'a, U, S>
 where
-    U: TensorTypeNumeric,
+    U: TensorType,
     S: TensorStorage<U> + TensorStorageMut<U>,
 {
     pub fn iter_mut(&'_ mut self) -> TensorIterMut<'_, U> {
-        let ndim = self.ndim();
+        // A 0-d tensor is iterated as a single element, an empty one not at all
+        let (shape, strides): (&[usize], &[usize]) = match self.ndim() {
+            _ if self.shape.contains(&0) => (&[0], &[0]),
+            0 => (&[1], &[0]),
+            _ => (&self.shape, &self.strides),
+        };
 
         TensorIterMut {
-            shape: &self.shape,
-            strides: &self.strides,
+            shape,
+            strides,
             offset: &self.offset,
             data: self.data.as_mut(),
-            index: vec![0; ndim],
+            index: vec![0; shape.len()],
             _u: PhantomData,
         }
     }
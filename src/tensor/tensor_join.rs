@@ -0,0 +1,185 @@
+use super::*;
+
+impl<'a, U> Tensor<'a, U>
+where
+    U: TensorType,
+{
+    /// Joins the tensors along an existing axis. All tensors need the same
+    /// number of dimensions and the same shape on every other axis.
+    pub fn concat(tensors: &[TensorView<'_, U>], axis: usize) -> Result<Tensor<'static, U>, Error> {
+        let first = tensors.first().ok_or_else(|| Error::InvalidParam {
+            err_msg: "Cannot concatenate an empty list of tensors".to_string(),
+        })?;
+
+        if axis >= first.ndim() {
+            return Err(Error::InvalidAxis {
+                axis,
+                ndim: first.ndim(),
+            });
+        }
+
+        let mut shape_r = first.shape();
+        shape_r[axis] = 0;
+
+        for tensor in tensors {
+            let same_shape = tensor.ndim() == first.ndim()
+                && (0..first.ndim())
+                    .all(|dim| dim == axis || tensor.shape[dim] == first.shape[dim]);
+
+            if !same_shape {
+                return Err(Error::ShapeMismatch {
+                    shape_a: first.shape(),
+                    shape_b: tensor.shape(),
+                });
+            }
+            shape_r[axis] += tensor.shape[axis];
+        }
+
+        // In row-major order, every index of the leading axes owns one
+        // contiguous block from each of the tensors, one after the other.
+        let outer: usize = shape_r[..axis].iter().product();
+        let blocks: Vec<usize> = tensors
+            .iter()
+            .map(|tensor| tensor.shape[axis..].iter().product())
+            .collect();
+
+        let mut iters: Vec<_> = tensors.iter().map(|tensor| tensor.iter()).collect();
+        let mut data_r = Vec::with_capacity(shape_r.iter().product());
+
+        for _ in 0..outer {
+            for (iter, &block) in iters.iter_mut().zip(&blocks) {
+                data_r.extend(iter.by_ref().take(block));
+            }
+        }
+
+        Ok(Tensor {
+            strides: first.compute_strides(&shape_r),
+            shape: shape_r,
+            offset: 0,
+            data: data_r,
+            _u: PhantomData,
+            _s: PhantomData,
+        })
+    }
+
+    /// Joins equally shaped tensors along a new axis inserted at `axis`
+    /// (`0..=ndim`).
+    pub fn stack(tensors: &[TensorView<'_, U>], axis: usize) -> Result<Tensor<'static, U>, Error> {
+        let first = tensors.first().ok_or_else(|| Error::InvalidParam {
+            err_msg: "Cannot stack an empty list of tensors".to_string(),
+        })?;
+
+        let mut views = Vec::with_capacity(tensors.len());
+
+        for tensor in tensors {
+            if tensor.shape != first.shape {
+                return Err(Error::ShapeMismatch {
+                    shape_a: first.shape(),
+                    shape_b: tensor.shape(),
+                });
+            }
+            views.push(tensor.unsqueeze(axis)?);
+        }
+
+        Tensor::concat(&views, axis)
+    }
+}
+
+impl<'a, U, S> TensorBase<'a, U, S>
+where
+    U: TensorType,
+    S: TensorStorage<U>,
+{
+    /// Splits the tensor along `axis` into views of the given sizes, which
+    /// must add up to the size of the axis.
+    pub fn split_sizes(
+        &'a self,
+        sizes: &[usize],
+        axis: usize,
+    ) -> Result<Vec<TensorView<'a, U>>, Error> {
+        if axis >= self.ndim() {
+            return Err(Error::InvalidAxis {
+                axis,
+                ndim: self.ndim(),
+            });
+        }
+
+        if sizes.iter().sum::<usize>() != self.shape[axis] {
+            let mut shape_b = self.shape();
+            shape_b[axis] = sizes.iter().sum();
+
+            return Err(Error::ShapeMismatch {
+                shape_a: self.shape(),
+                shape_b,
+            });
+        }
+
+        let mut offset = self.offset;
+        let mut views = Vec::with_capacity(sizes.len());
+
+        for &size in sizes {
+            let mut shape = self.shape();
+            shape[axis] = size;
+
+            views.push(TensorView {
+                shape,
+                strides: self.strides(),
+                offset,
+                data: self.data.as_ref(),
+                _u: PhantomData,
+                _s: PhantomData,
+            });
+            offset += size * self.strides[axis];
+        }
+
+        Ok(views)
+    }
+
+    /// Splits the tensor along `axis` into views of `split_size` elements.
+    /// The last view is smaller if the axis doesn't divide evenly.
+    pub fn split(
+        &'a self,
+        split_size: usize,
+        axis: usize,
+    ) -> Result<Vec<TensorView<'a, U>>, Error> {
+        if split_size == 0 {
+            return Err(Error::InvalidParam {
+                err_msg: "split_size must be greater than 0".to_string(),
+            });
+        }
+
+        if axis >= self.ndim() {
+            return Err(Error::InvalidAxis {
+                axis,
+                ndim: self.ndim(),
+            });
+        }
+
+        let len = self.shape[axis];
+        let sizes: Vec<usize> = (0..len)
+            .step_by(split_size)
+            .map(|start| split_size.min(len - start))
+            .collect();
+
+        self.split_sizes(&sizes, axis)
+    }
+
+    /// Splits the tensor along `axis` into at most `chunks` views of equal
+    /// size, except for a smaller last one.
+    pub fn chunk(&'a self, chunks: usize, axis: usize) -> Result<Vec<TensorView<'a, U>>, Error> {
+        if chunks == 0 {
+            return Err(Error::InvalidParam {
+                err_msg: "chunks must be greater than 0".to_string(),
+            });
+        }
+
+        if axis >= self.ndim() {
+            return Err(Error::InvalidAxis {
+                axis,
+                ndim: self.ndim(),
+            });
+        }
+
+        self.split(self.shape[axis].div_ceil(chunks).max(1), axis)
+    }
+}
@@ -1,8 +1,10 @@
 use super::*;
 use indexmap::IndexMap;
-use num_traits::Float;
-use std::cmp::Eq;
+use num_traits::{Float, NumCast};
+use std::cmp::{Eq, Ordering};
 use std::hash::Hash;
+use std::ops::Range;
+use tensor_cast::unravel;
 
 impl<'a> Tensor<'a, u8> {
     pub fn from_one_hot_enc<L: Eq + Hash>(labels: &Vec<L>) -> Result<Self, Error> {
@@ -43,7 +45,7 @@ impl<'a> Tensor<'a, u8> {
 
 impl<'a, U, S> TensorBase<'a, U, S>
 where
-    U: TensorTypeNumeric,
+    U: TensorTypeOrdered,
     S: TensorStorage<U> + TensorStorageMut<U>,
 {
     pub fn relu(&mut self) -> Result<bool, Error> {
@@ -61,9 +63,12 @@ where
     F: TensorTypeFloat,
     S: TensorStorage<F> + TensorStorageMut<F>,
 {
-    pub fn softmax(&'a mut self, axis: usize) -> Result<TensorViewMut<'a, F>, Error> {
+    pub fn softmax(&'a mut self, axis: usize) -> Result<TensorViewMut<'a, F>, Error>
+    where
+        F: 'static,
+    {
         let mut view = self.axis_mut(axis)?;
-        let max = view.max();
+        let max = view.max()?;
         let mut sum_exps = F::default();
 
         for val in view.iter_mut() {
@@ -78,3 +83,792 @@ where
         Ok(view)
     }
 }
+
+/// Row-major `n x n` identity matrix.
+pub(crate) fn identity<F: TensorTypeFloat>(n: usize) -> Vec<F> {
+    let mut eye = vec![F::zero(); n * n];
+    for i in 0..n {
+        eye[i * n + i] = F::one();
+    }
+    eye
+}
+
+/// LU factors of a row-major `n x n` matrix with partial pivoting, so that
+/// row `i` of `P * A` is row `perm[i]` of `A`. `L` (unit diagonal, below the
+/// diagonal) and `U` (on and above the diagonal) share the `lu` buffer.
+pub(crate) struct LuFactors<F> {
+    pub(crate) lu: Vec<F>,
+    pub(crate) perm: Vec<usize>,
+    pub(crate) n: usize,
+    /// The permutation has an odd number of row swaps.
+    pub(crate) odd: bool,
+    /// A pivot vanished, so `U` has a zero on its diagonal.
+    pub(crate) singular: bool,
+}
+
+impl<F: TensorTypeFloat> LuFactors<F> {
+    pub(crate) fn new(mut lu: Vec<F>, n: usize) -> Self {
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut odd = false;
+        let mut singular = false;
+
+        for k in 0..n {
+            // Pick the largest remaining element of the column as the pivot
+            let mut p = k;
+            for i in k + 1..n {
+                if Float::abs(lu[i * n + k]) > Float::abs(lu[p * n + k]) {
+                    p = i;
+                }
+            }
+
+            if p != k {
+                for j in 0..n {
+                    lu.swap(k * n + j, p * n + j);
+                }
+                perm.swap(k, p);
+                odd = !odd;
+            }
+
+            let pivot = lu[k * n + k];
+            if pivot == F::zero() {
+                singular = true;
+                continue;
+            }
+
+            for i in k + 1..n {
+                let factor = lu[i * n + k] / pivot;
+                lu[i * n + k] = factor;
+                for j in k + 1..n {
+                    let val = lu[k * n + j];
+                    lu[i * n + j] -= factor * val;
+                }
+            }
+        }
+
+        LuFactors {
+            lu,
+            perm,
+            n,
+            odd,
+            singular,
+        }
+    }
+
+    /// Solves `A * X = B` for a row-major `n x k` right-hand side.
+    pub(crate) fn solve(&self, b: &[F], k: usize) -> Vec<F> {
+        let (lu, n) = (&self.lu, self.n);
+        let mut x = Vec::with_capacity(n * k);
+        for &row in &self.perm {
+            x.extend_from_slice(&b[row * k..(row + 1) * k]);
+        }
+
+        // Forward substitution with the unit lower triangle
+        for i in 0..n {
+            for j in 0..i {
+                let l = lu[i * n + j];
+                for c in 0..k {
+                    let val = x[j * k + c];
+                    x[i * k + c] -= l * val;
+                }
+            }
+        }
+
+        // Back substitution with the upper triangle
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                let u = lu[i * n + j];
+                for c in 0..k {
+                    let val = x[j * k + c];
+                    x[i * k + c] -= u * val;
+                }
+            }
+            let pivot = lu[i * n + i];
+            for val in x[i * k..(i + 1) * k].iter_mut() {
+                *val /= pivot;
+            }
+        }
+
+        x
+    }
+
+    pub(crate) fn det(&self) -> F {
+        let det = (0..self.n).fold(F::one(), |det, i| det * self.lu[i * self.n + i]);
+        match self.odd {
+            true => -det,
+            false => det,
+        }
+    }
+
+    /// Sign and natural log of the absolute value of the determinant.
+    pub(crate) fn slogdet(&self) -> (F, F) {
+        if self.singular {
+            return (F::zero(), F::neg_infinity());
+        }
+
+        let mut sign = if self.odd { -F::one() } else { F::one() };
+        let mut logabsdet = F::zero();
+        for i in 0..self.n {
+            let pivot = self.lu[i * self.n + i];
+            sign *= Float::signum(pivot);
+            logabsdet += Float::ln(Float::abs(pivot));
+        }
+
+        (sign, logabsdet)
+    }
+}
+
+/// Householder reflection `I - 2 * v * v^T / (v^T * v)` acting on rows
+/// `row..row + v.len()`.
+struct Reflector<F> {
+    row: usize,
+    v: Vec<F>,
+    vnorm2: F,
+}
+
+impl<F: TensorTypeFloat> Reflector<F> {
+    /// Reflects the columns `cols` of the row-major `x` with `k` columns.
+    fn apply(&self, x: &mut [F], k: usize, cols: Range<usize>) {
+        for c in cols {
+            let mut dot = F::zero();
+            for (i, &vi) in self.v.iter().enumerate() {
+                dot += vi * x[(self.row + i) * k + c];
+            }
+            let factor = (dot + dot) / self.vnorm2;
+            for (i, &vi) in self.v.iter().enumerate() {
+                x[(self.row + i) * k + c] -= factor * vi;
+            }
+        }
+    }
+}
+
+/// Householder QR factors of a row-major `m x n` matrix. `r` holds the
+/// `m x n` upper triangular factor and `Q` is the product of the reflectors,
+/// which is only formed on demand.
+pub(crate) struct QrFactors<F> {
+    pub(crate) r: Vec<F>,
+    reflectors: Vec<Reflector<F>>,
+    pub(crate) m: usize,
+    pub(crate) n: usize,
+}
+
+impl<F: TensorTypeFloat> QrFactors<F> {
+    pub(crate) fn new(mut r: Vec<F>, m: usize, n: usize) -> Self {
+        let mut reflectors = Vec::new();
+
+        // The last row of a wide matrix needs no reflection
+        for j in 0..n.min(m.saturating_sub(1)) {
+            let norm =
+                Float::sqrt((j..m).fold(F::zero(), |acc, i| acc + r[i * n + j] * r[i * n + j]));
+            if norm == F::zero() {
+                continue;
+            }
+
+            // Reflect onto the axis with the opposite sign to avoid cancellation
+            let alpha = if r[j * n + j] < F::zero() {
+                norm
+            } else {
+                -norm
+            };
+            let mut v: Vec<F> = (j..m).map(|i| r[i * n + j]).collect();
+            v[0] -= alpha;
+            let vnorm2 = v.iter().fold(F::zero(), |acc, &vi| acc + vi * vi);
+
+            let reflector = Reflector { row: j, v, vnorm2 };
+            reflector.apply(&mut r, n, j + 1..n);
+            r[j * n + j] = alpha;
+            for i in j + 1..m {
+                r[i * n + j] = F::zero();
+            }
+            reflectors.push(reflector);
+        }
+
+        QrFactors {
+            r,
+            reflectors,
+            m,
+            n,
+        }
+    }
+
+    /// Replaces the row-major `m x k` matrix `x` with `Q^T * x`.
+    pub(crate) fn apply_qt(&self, x: &mut [F], k: usize) {
+        for reflector in &self.reflectors {
+            reflector.apply(x, k, 0..k);
+        }
+    }
+
+    /// Replaces the row-major `m x k` matrix `x` with `Q * x`.
+    pub(crate) fn apply_q(&self, x: &mut [F], k: usize) {
+        for reflector in self.reflectors.iter().rev() {
+            reflector.apply(x, k, 0..k);
+        }
+    }
+
+    /// The first `cols` columns of `Q`.
+    pub(crate) fn q(&self, cols: usize) -> Vec<F> {
+        let mut q = vec![F::zero(); self.m * cols];
+        for i in 0..cols.min(self.m) {
+            q[i * cols + i] = F::one();
+        }
+        self.apply_q(&mut q, cols);
+        q
+    }
+
+    /// The leading `p x p` block of `R` is numerically singular, judged
+    /// relative to its largest diagonal element as in LAPACK's `gelsy`.
+    pub(crate) fn rank_deficient(&self, p: usize) -> bool {
+        let mut diag = (0..p).map(|i| Float::abs(self.r[i * self.n + i]));
+        let max = diag.clone().fold(F::zero(), Float::max);
+        let size = <F as NumCast>::from(self.m.max(self.n)).unwrap_or_else(F::one);
+        let tol = max * F::epsilon() * size;
+        diag.any(|val| val <= tol)
+    }
+}
+
+/// Cholesky factor `L` of a row-major `n x n` matrix, reading only its lower
+/// triangle. `None` if the matrix isn't positive definite.
+pub(crate) fn cholesky<F: TensorTypeFloat>(a: &[F], n: usize) -> Option<Vec<F>> {
+    let mut l = vec![F::zero(); n * n];
+
+    for j in 0..n {
+        let mut diag = a[j * n + j];
+        for k in 0..j {
+            diag -= l[j * n + k] * l[j * n + k];
+        }
+        // NaN is not greater than zero either
+        if diag.partial_cmp(&F::zero()) != Some(Ordering::Greater) {
+            return None;
+        }
+        let diag = Float::sqrt(diag);
+        l[j * n + j] = diag;
+
+        for i in j + 1..n {
+            let mut val = a[i * n + j];
+            for k in 0..j {
+                val -= l[i * n + k] * l[j * n + k];
+            }
+            l[i * n + j] = val / diag;
+        }
+    }
+
+    Some(l)
+}
+
+/// Solves `T * X = B` (or `T^T * X = B` with `trans`) in place for a
+/// row-major `n x n` triangular `t` and an `n x k` right-hand side `x`. Only
+/// the triangle selected by `lower` is read, and with `unit` the diagonal is
+/// taken to be all ones.
+pub(crate) fn solve_tri<F: TensorTypeFloat>(
+    t: &[F],
+    n: usize,
+    x: &mut [F],
+    k: usize,
+    lower: bool,
+    trans: bool,
+    unit: bool,
+) {
+    // The transpose of a lower triangle is upper and vice versa
+    let forward = lower != trans;
+    let elem = |i: usize, j: usize| if trans { t[j * n + i] } else { t[i * n + j] };
+
+    for step in 0..n {
+        let i = if forward { step } else { n - 1 - step };
+        let cols = if forward { 0..i } else { i + 1..n };
+        for j in cols {
+            let t_ij = elem(i, j);
+            for c in 0..k {
+                let val = x[j * k + c];
+                x[i * k + c] -= t_ij * val;
+            }
+        }
+        if !unit {
+            let diag = t[i * n + i];
+            for val in x[i * k..(i + 1) * k].iter_mut() {
+                *val /= diag;
+            }
+        }
+    }
+}
+
+/// Solves `A * X = B` in place for a banded row-major `n x n` matrix `A` with
+/// `l` sub- and `u` superdiagonals in the LAPACK band storage `ab` (as in
+/// `solve_banded`), and an `n x k` right-hand side `x`. Gaussian elimination
+/// with partial pivoting keeps the fill-in within `l + u` superdiagonals.
+/// Returns `false` if `A` is singular.
+pub(crate) fn solve_band<F: TensorTypeFloat>(
+    ab: &[F],
+    n: usize,
+    l: usize,
+    u: usize,
+    x: &mut [F],
+    k: usize,
+) -> bool {
+    // Row i holds columns i - l ..= i + l + u, the extra l for the fill-in
+    let width = 2 * l + u + 1;
+    let at = |i: usize, j: usize| i * width + j + l - i;
+    let mut band = vec![F::zero(); n * width];
+    for j in 0..n {
+        for i in j.saturating_sub(u)..n.min(j + l + 1) {
+            band[at(i, j)] = ab[(u + i - j) * n + j];
+        }
+    }
+
+    for c in 0..n {
+        let last_row = (n - 1).min(c + l);
+        let last_col = (n - 1).min(c + l + u);
+
+        let mut p = c;
+        for i in c + 1..=last_row {
+            if Float::abs(band[at(i, c)]) > Float::abs(band[at(p, c)]) {
+                p = i;
+            }
+        }
+        if band[at(p, c)] == F::zero() {
+            return false;
+        }
+        if p != c {
+            for j in c..=last_col {
+                band.swap(at(c, j), at(p, j));
+            }
+            for col in 0..k {
+                x.swap(c * k + col, p * k + col);
+            }
+        }
+
+        let pivot = band[at(c, c)];
+        for i in c + 1..=last_row {
+            let factor = band[at(i, c)] / pivot;
+            if factor == F::zero() {
+                continue;
+            }
+            for j in c + 1..=last_col {
+                let val = band[at(c, j)];
+                band[at(i, j)] -= factor * val;
+            }
+            for col in 0..k {
+                let val = x[c * k + col];
+                x[i * k + col] -= factor * val;
+            }
+        }
+    }
+
+    for i in (0..n).rev() {
+        for j in i + 1..n.min(i + l + u + 1) {
+            let a_ij = band[at(i, j)];
+            for col in 0..k {
+                let val = x[j * k + col];
+                x[i * k + col] -= a_ij * val;
+            }
+        }
+        let diag = band[at(i, i)];
+        for val in x[i * k..(i + 1) * k].iter_mut() {
+            *val /= diag;
+        }
+    }
+
+    true
+}
+
+/// Shape of the solutions for the right-hand sides of `rhs_batch`, with `n`
+/// unknowns.
+fn rhs_shape(mut batch: Vec<usize>, n: usize, k: usize, ndim_b: usize) -> Vec<usize> {
+    match ndim_b {
+        1 => batch.push(n),
+        _ => batch.extend_from_slice(&[n, k]),
+    }
+    batch
+}
+
+impl<'a, F, S> TensorBase<'a, F, S>
+where
+    F: TensorTypeFloat,
+    S: TensorStorage<F>,
+{
+    /// Splits the shape into the leading batch axes and the size `m x n` of
+    /// the trailing matrices.
+    pub(crate) fn matrix_batch(&self) -> Result<(Vec<usize>, usize, usize), Error> {
+        let ndim = self.ndim();
+        if ndim < 2 {
+            return Err(Error::DimensionMismatch {
+                tensor_dim: ndim,
+                dim: 2,
+            });
+        }
+
+        Ok((
+            self.shape[..ndim - 2].to_vec(),
+            self.shape[ndim - 2],
+            self.shape[ndim - 1],
+        ))
+    }
+
+    /// Splits the shape into the leading batch axes and the size `n` of the
+    /// trailing `n x n` matrices.
+    pub(crate) fn square_batch(&self) -> Result<(Vec<usize>, usize), Error> {
+        let (batch, m, n) = self.matrix_batch()?;
+        if m != n {
+            return Err(Error::InvalidParam {
+                err_msg: format!("Expected square matrices, got shape {:?}", self.shape),
+            });
+        }
+
+        Ok((batch, n))
+    }
+
+    /// Broadcasts the batch axes of `self` (`[..., m, n]`) against those of
+    /// the right-hand sides `tensor_b` (`[rows]` or `[..., rows, k]`).
+    /// Returns the batch shape, `k` and the data of both operands in
+    /// row-major batch order.
+    #[allow(clippy::type_complexity)]
+    fn rhs_batch(
+        &self,
+        tensor_b: &TensorView<'_, F>,
+        rows: usize,
+    ) -> Result<(Vec<usize>, usize, Vec<F>, Vec<F>), Error> {
+        let (batch_a, m, n) = self.matrix_batch()?;
+        let ndim_b = tensor_b.ndim();
+        let shape_err = || Error::ShapeMismatch {
+            shape_a: self.shape(),
+            shape_b: tensor_b.shape(),
+        };
+
+        let (batch_b, k) = match ndim_b {
+            0 => return Err(shape_err()),
+            1 => (vec![], 1),
+            _ => (
+                tensor_b.shape[..ndim_b - 2].to_vec(),
+                tensor_b.shape[ndim_b - 1],
+            ),
+        };
+        let rows_b = tensor_b.shape[if ndim_b == 1 { 0 } else { ndim_b - 2 }];
+        if rows_b != rows {
+            return Err(shape_err());
+        }
+
+        let batch = broadcast_shapes(&[&batch_a, &batch_b])?;
+        let mut shape_a = batch.clone();
+        shape_a.extend_from_slice(&[m, n]);
+        let mut shape_b = batch.clone();
+        match ndim_b {
+            1 => shape_b.push(rows),
+            _ => shape_b.extend_from_slice(&[rows, k]),
+        }
+
+        let data_a = self.broadcast_view(&shape_a)?.data();
+        let data_b = tensor_b.broadcast_view(&shape_b)?.data();
+        Ok((batch, k, data_a, data_b))
+    }
+
+    /// LU factors of every matrix in the batch, in row-major batch order.
+    fn lu_batch(&self) -> Result<(Vec<usize>, Vec<LuFactors<F>>), Error> {
+        let (batch, n) = self.square_batch()?;
+        let data = self.data();
+        let factors = (0..batch.iter().product())
+            .map(|b| LuFactors::new(data[b * n * n..(b + 1) * n * n].to_vec(), n))
+            .collect();
+
+        Ok((batch, factors))
+    }
+
+    /// LU decomposition with partial pivoting of a (batch of) square
+    /// matrices. Returns `(P, L, U)` with `A = P * L * U`, where `L` is unit
+    /// lower triangular and `U` is upper triangular. Singular matrices are
+    /// factorized as well, with a zero on the diagonal of `U`.
+    #[allow(clippy::type_complexity)]
+    pub fn lu(
+        &self,
+    ) -> Result<(Tensor<'static, F>, Tensor<'static, F>, Tensor<'static, F>), Error> {
+        let (batch, factors) = self.lu_batch()?;
+        let n = self.shape[self.ndim() - 1];
+        let mut data_p = vec![F::zero(); factors.len() * n * n];
+        let mut data_l = vec![F::zero(); factors.len() * n * n];
+        let mut data_u = vec![F::zero(); factors.len() * n * n];
+
+        for (b, factor) in factors.iter().enumerate() {
+            let base = b * n * n;
+            for i in 0..n {
+                data_p[base + factor.perm[i] * n + i] = F::one();
+                for j in 0..n {
+                    let val = factor.lu[i * n + j];
+                    match j.cmp(&i) {
+                        Ordering::Less => data_l[base + i * n + j] = val,
+                        Ordering::Equal => {
+                            data_l[base + i * n + j] = F::one();
+                            data_u[base + i * n + j] = val;
+                        }
+                        Ordering::Greater => data_u[base + i * n + j] = val,
+                    }
+                }
+            }
+        }
+
+        let mut shape = batch;
+        shape.extend_from_slice(&[n, n]);
+
+        Ok((
+            Tensor::from_shape(&shape, data_p)?,
+            Tensor::from_shape(&shape, data_l)?,
+            Tensor::from_shape(&shape, data_u)?,
+        ))
+    }
+
+    /// Solves `A * X = B` for `X`, where `self` is `A` with shape `[..., n, n]`.
+    /// A 1-D `tensor_b` of size `n` is a single right-hand side vector,
+    /// otherwise `tensor_b` holds `k` right-hand sides with shape
+    /// `[..., n, k]`. Batch axes are broadcast as in `matmul`.
+    pub fn solve(&self, tensor_b: &TensorView<'_, F>) -> Result<Tensor<'static, F>, Error> {
+        let (_, n) = self.square_batch()?;
+        let (batch, k, data_a, data_b) = self.rhs_batch(tensor_b, n)?;
+        let mut data_x = Vec::with_capacity(data_b.len());
+
+        for b in 0..batch.iter().product() {
+            let factors = LuFactors::new(data_a[b * n * n..(b + 1) * n * n].to_vec(), n);
+            if factors.singular {
+                return Err(Error::SingularMatrix {
+                    index: unravel(b, &batch),
+                });
+            }
+            data_x.extend(factors.solve(&data_b[b * n * k..(b + 1) * n * k], k));
+        }
+
+        Tensor::from_shape(&rhs_shape(batch, n, k, tensor_b.ndim()), data_x)
+    }
+
+    /// Determinant of a (batch of) square matrices. The result has the shape
+    /// of the batch axes.
+    pub fn det(&self) -> Result<Tensor<'static, F>, Error> {
+        let (batch, factors) = self.lu_batch()?;
+        Tensor::from_shape(&batch, factors.iter().map(LuFactors::det).collect())
+    }
+
+    /// Sign and natural log of the absolute value of the determinant, which
+    /// doesn't under- or overflow for large matrices. Singular matrices give
+    /// a sign of 0 and a log of `-inf`.
+    pub fn slogdet(&self) -> Result<(Tensor<'static, F>, Tensor<'static, F>), Error> {
+        let (batch, factors) = self.lu_batch()?;
+        let (signs, logs) = factors.iter().map(LuFactors::slogdet).unzip();
+
+        Ok((
+            Tensor::from_shape(&batch, signs)?,
+            Tensor::from_shape(&batch, logs)?,
+        ))
+    }
+
+    /// Inverse of a (batch of) square matrices.
+    pub fn inv(&self) -> Result<Tensor<'static, F>, Error> {
+        let (batch, factors) = self.lu_batch()?;
+        let n = self.shape[self.ndim() - 1];
+        let eye = Tensor::<F>::eye(n, n)?;
+        let mut data = Vec::with_capacity(factors.len() * n * n);
+
+        for (b, factor) in factors.iter().enumerate() {
+            if factor.singular {
+                return Err(Error::SingularMatrix {
+                    index: unravel(b, &batch),
+                });
+            }
+            data.extend(factor.solve(&eye.data, n));
+        }
+
+        Tensor::from_shape(&self.shape, data)
+    }
+
+    /// Householder QR decomposition of a (batch of) `m x n` matrices,
+    /// `A = Q * R` with orthonormal columns in `Q` and an upper triangular
+    /// `R`. `mode` selects the reduced or complete factors.
+    pub fn qr(&self, mode: QrMode) -> Result<(Tensor<'static, F>, Tensor<'static, F>), Error> {
+        let (batch, m, n) = self.matrix_batch()?;
+        let rows = match mode {
+            QrMode::Reduced => m.min(n),
+            QrMode::Complete => m,
+        };
+        let data = self.data();
+        let nbatch: usize = batch.iter().product();
+        let mut data_q = Vec::with_capacity(nbatch * m * rows);
+        let mut data_r = Vec::with_capacity(nbatch * rows * n);
+
+        for b in 0..nbatch {
+            let factors = QrFactors::new(data[b * m * n..(b + 1) * m * n].to_vec(), m, n);
+            data_q.extend(factors.q(rows));
+            data_r.extend_from_slice(&factors.r[..rows * n]);
+        }
+
+        let mut shape_q = batch.clone();
+        shape_q.extend_from_slice(&[m, rows]);
+        let mut shape_r = batch;
+        shape_r.extend_from_slice(&[rows, n]);
+
+        Ok((
+            Tensor::from_shape(&shape_q, data_q)?,
+            Tensor::from_shape(&shape_r, data_r)?,
+        ))
+    }
+
+    /// Cholesky factor `L` of a (batch of) symmetric positive definite
+    /// matrices, `A = L * L^T`. Only the lower triangle of `A` is read.
+    pub fn cholesky(&self) -> Result<Tensor<'static, F>, Error> {
+        let (batch, n) = self.square_batch()?;
+        let data = self.data();
+        let mut data_l = Vec::with_capacity(data.len());
+
+        for b in 0..batch.iter().product() {
+            match cholesky(&data[b * n * n..(b + 1) * n * n], n) {
+                Some(l) => data_l.extend(l),
+                None => {
+                    return Err(Error::NotPositiveDefinite {
+                        index: unravel(b, &batch),
+                    })
+                }
+            }
+        }
+
+        Tensor::from_shape(&self.shape, data_l)
+    }
+
+    /// Least-squares solution of `A * X = B` via QR, where `self` is `A` with
+    /// shape `[..., m, n]` and `tensor_b` is shaped as in `solve`. Tall
+    /// systems give the solution with the smallest residual, wide ones the
+    /// solution with the smallest norm. `A` must have full rank, otherwise
+    /// `Error::SingularMatrix` is returned.
+    pub fn lstsq(&self, tensor_b: &TensorView<'_, F>) -> Result<Tensor<'static, F>, Error> {
+        let (_, m, n) = self.matrix_batch()?;
+        let (batch, k, data_a, data_b) = self.rhs_batch(tensor_b, m)?;
+        let p = m.min(n);
+        let mut data_x = Vec::with_capacity(batch.iter().product::<usize>() * n * k);
+
+        for b in 0..batch.iter().product() {
+            let a = &data_a[b * m * n..(b + 1) * m * n];
+            let mut x = data_b[b * m * k..(b + 1) * m * k].to_vec();
+
+            let factors = match m >= n {
+                true => QrFactors::new(a.to_vec(), m, n),
+                // Factorize A^T for the minimum norm solution
+                false => {
+                    let a_t = (0..n * m).map(|pos| a[(pos % m) * n + pos / m]).collect();
+                    QrFactors::new(a_t, n, m)
+                }
+            };
+            if factors.rank_deficient(p) {
+                return Err(Error::SingularMatrix {
+                    index: unravel(b, &batch),
+                });
+            }
+
+            let r = &factors.r[..p * p];
+            match m >= n {
+                // R * X = Q^T * B
+                true => {
+                    factors.apply_qt(&mut x, k);
+                    x.truncate(n * k);
+                    solve_tri(r, n, &mut x, k, false, false, false);
+                }
+                // R^T * Y = B, X = Q * [Y; 0]
+                false => {
+                    solve_tri(r, m, &mut x, k, false, true, false);
+                    x.resize(n * k, F::zero());
+                    factors.apply_q(&mut x, k);
+                }
+            }
+            data_x.extend(x);
+        }
+
+        Tensor::from_shape(&rhs_shape(batch, n, k, tensor_b.ndim()), data_x)
+    }
+
+    /// Solves `A * X = B`, or `A^T * X = B` with `trans`, where `self` is a
+    /// (batch of) triangular `A` with shape `[..., n, n]` and `tensor_b` is
+    /// shaped as in `solve`. Only the triangle selected by `uplo` is read,
+    /// and with `unit_diagonal` the diagonal is taken to be all ones.
+    pub fn solve_triangular(
+        &self,
+        tensor_b: &TensorView<'_, F>,
+        uplo: Triangle,
+        trans: bool,
+        unit_diagonal: bool,
+    ) -> Result<Tensor<'static, F>, Error> {
+        let (_, n) = self.square_batch()?;
+        let (batch, k, data_a, mut data_x) = self.rhs_batch(tensor_b, n)?;
+
+        for b in 0..batch.iter().product() {
+            let a = &data_a[b * n * n..(b + 1) * n * n];
+            if !unit_diagonal && (0..n).any(|i| a[i * n + i] == F::zero()) {
+                return Err(Error::SingularMatrix {
+                    index: unravel(b, &batch),
+                });
+            }
+            let x = &mut data_x[b * n * k..(b + 1) * n * k];
+            solve_tri(a, n, x, k, uplo == Triangle::Lower, trans, unit_diagonal);
+        }
+
+        Tensor::from_shape(&rhs_shape(batch, n, k, tensor_b.ndim()), data_x)
+    }
+
+    /// Solves `A * X = B` for a (batch of) banded `n x n` matrices `A` with
+    /// `l` sub- and `u` superdiagonals. `self` holds `A` in LAPACK band
+    /// storage with shape `[..., l + u + 1, n]`, where `A[i][j]` is stored at
+    /// `[u + i - j, j]`, and `tensor_b` is shaped as in `solve`.
+    pub fn solve_banded(
+        &self,
+        l: usize,
+        u: usize,
+        tensor_b: &TensorView<'_, F>,
+    ) -> Result<Tensor<'static, F>, Error> {
+        let (_, rows, n) = self.matrix_batch()?;
+        if rows != l + u + 1 {
+            return Err(Error::InvalidParam {
+                err_msg: format!(
+                    "Expected {} rows of band storage for ({}, {}) diagonals, got shape {:?}",
+                    l + u + 1,
+                    l,
+                    u,
+                    self.shape
+                ),
+            });
+        }
+
+        let (batch, k, data_ab, mut data_x) = self.rhs_batch(tensor_b, n)?;
+        for b in 0..batch.iter().product() {
+            let ab = &data_ab[b * rows * n..(b + 1) * rows * n];
+            if !solve_band(ab, n, l, u, &mut data_x[b * n * k..(b + 1) * n * k], k) {
+                return Err(Error::SingularMatrix {
+                    index: unravel(b, &batch),
+                });
+            }
+        }
+
+        Tensor::from_shape(&rhs_shape(batch, n, k, tensor_b.ndim()), data_x)
+    }
+}
+
+impl<'a, F> Tensor<'a, F>
+where
+    F: TensorTypeFloat,
+{
+    /// Solves `A * X = B` for a tridiagonal `n x n` matrix `A` with the
+    /// subdiagonal `dl`, diagonal `d` and superdiagonal `du`, with `n - 1`,
+    /// `n` and `n - 1` elements. `tensor_b` is shaped as in `solve`.
+    pub fn solve_tridiagonal(
+        dl: &TensorView<'_, F>,
+        d: &TensorView<'_, F>,
+        du: &TensorView<'_, F>,
+        tensor_b: &TensorView<'_, F>,
+    ) -> Result<Tensor<'static, F>, Error> {
+        let n = d.nelems();
+        for diag in [dl, du] {
+            if d.ndim() != 1 || diag.ndim() != 1 || diag.nelems() + 1 != n {
+                return Err(Error::ShapeMismatch {
+                    shape_a: diag.shape(),
+                    shape_b: d.shape(),
+                });
+            }
+        }
+
+        let mut data = vec![F::zero()];
+        data.extend(du.iter());
+        data.extend(d.iter());
+        data.extend(dl.iter());
+        data.push(F::zero());
+
+        Tensor::from_shape(&[3, n], data)?.solve_banded(1, 1, tensor_b)
+    }
+}
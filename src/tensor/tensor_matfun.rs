@@ -0,0 +1,279 @@
+use super::*;
+use num_traits::{Float, NumCast};
+use tensor_cast::unravel;
+use tensor_linalg::{identity, LuFactors};
+
+/// Iterations of the Denman-Beavers square root and of the log series
+/// before giving up on convergence.
+const MAX_ITERS: usize = 100;
+
+/// Failure of a matrix function on a single matrix of a batch.
+enum MatFunError {
+    Singular,
+    NoConvergence,
+}
+
+impl MatFunError {
+    fn at(self, index: Vec<usize>) -> Error {
+        match self {
+            MatFunError::Singular => Error::SingularMatrix { index },
+            MatFunError::NoConvergence => Error::NoConvergence { index },
+        }
+    }
+}
+
+/// Product of two row-major `n x n` matrices.
+fn matmul<F: TensorTypeFloat>(a: &[F], b: &[F], n: usize) -> Vec<F> {
+    let mut c = vec![F::zero(); n * n];
+    for i in 0..n {
+        for l in 0..n {
+            let a_il = a[i * n + l];
+            for j in 0..n {
+                c[i * n + j] += a_il * b[l * n + j];
+            }
+        }
+    }
+    c
+}
+
+fn inv<F: TensorTypeFloat>(a: &[F], n: usize) -> Result<Vec<F>, MatFunError> {
+    let factors = LuFactors::new(a.to_vec(), n);
+    match factors.singular {
+        true => Err(MatFunError::Singular),
+        false => Ok(factors.solve(&identity(n), n)),
+    }
+}
+
+/// Maximum absolute row sum.
+fn norm_inf<F: TensorTypeFloat>(a: &[F], n: usize) -> F {
+    a.chunks(n.max(1)).fold(F::zero(), |norm, row| {
+        Float::max(
+            norm,
+            row.iter()
+                .fold(F::zero(), |acc, &val| acc + Float::abs(val)),
+        )
+    })
+}
+
+/// `norm_inf(a - I)`.
+fn dist_eye<F: TensorTypeFloat>(a: &[F], n: usize) -> F {
+    let diff: Vec<F> = (0..n * n)
+        .map(|pos| match pos / n == pos % n {
+            true => a[pos] - F::one(),
+            false => a[pos],
+        })
+        .collect();
+    norm_inf(&diff, n)
+}
+
+/// Matrix exponential by scaling and squaring with a diagonal Pade
+/// approximant of degree 6 (Golub & Van Loan, Algorithm 11.3.1).
+/// Non-finite elements leave no norm to scale by and fail to converge.
+fn expm<F: TensorTypeFloat>(a: &[F], n: usize) -> Result<Vec<F>, MatFunError> {
+    const Q: usize = 6;
+    if a.iter().any(|val| !val.is_finite()) {
+        return Err(MatFunError::NoConvergence);
+    }
+    let norm = norm_inf(a, n);
+
+    // Scale so that the norm is at most 1/2
+    let half = <F as NumCast>::from(0.5).unwrap();
+    let squarings = match norm > half {
+        true => Float::ceil(Float::log2(norm / half))
+            .to_usize()
+            .unwrap_or(0),
+        false => 0,
+    };
+    let scale = Float::powi(<F as NumCast>::from(2).unwrap(), -(squarings as i32));
+    let a: Vec<F> = a.iter().map(|&val| val * scale).collect();
+
+    let mut x = a.clone();
+    let mut c = half;
+    let mut num = identity(n);
+    let mut den = identity(n);
+    for (pos, &val) in a.iter().enumerate() {
+        num[pos] += c * val;
+        den[pos] -= c * val;
+    }
+
+    for k in 2..=Q {
+        let ratio = <F as NumCast>::from((Q - k + 1) as f64 / (k * (2 * Q - k + 1)) as f64);
+        c *= ratio.unwrap();
+        x = matmul(&a, &x, n);
+        let sign = if k % 2 == 0 { F::one() } else { -F::one() };
+        for (pos, &val) in x.iter().enumerate() {
+            num[pos] += c * val;
+            den[pos] += sign * c * val;
+        }
+    }
+
+    // The denominator is well conditioned for the scaled norm
+    let factors = LuFactors::new(den, n);
+    if factors.singular {
+        return Err(MatFunError::Singular);
+    }
+    let mut e = factors.solve(&num, n);
+    for _ in 0..squarings {
+        e = matmul(&e, &e, n);
+    }
+    Ok(e)
+}
+
+/// Principal square root by the product form of the Denman-Beavers
+/// iteration, which converges for matrices without eigenvalues on the
+/// closed negative real axis.
+fn sqrtm<F: TensorTypeFloat>(a: &[F], n: usize) -> Result<Vec<F>, MatFunError> {
+    let half = <F as NumCast>::from(0.5).unwrap();
+    let size = <F as NumCast>::from(n).unwrap_or_else(F::one);
+    let mut m = a.to_vec();
+    let mut y = a.to_vec();
+
+    for _ in 0..MAX_ITERS {
+        let m_inv = inv(&m, n)?;
+
+        // Y = Y * (I + M^-1) / 2, M = (I + (M + M^-1) / 2) / 2
+        let mut factor = m_inv.clone();
+        for i in 0..n {
+            factor[i * n + i] += F::one();
+        }
+        let y_next: Vec<F> = matmul(&y, &factor, n)
+            .into_iter()
+            .map(|val| val * half)
+            .collect();
+        for (pos, val) in m.iter_mut().enumerate() {
+            *val = half * (*val + m_inv[pos]) * half;
+        }
+        for i in 0..n {
+            m[i * n + i] += half;
+        }
+
+        let diff: Vec<F> = y_next.iter().zip(&y).map(|(&a, &b)| a - b).collect();
+        let done = norm_inf(&diff, n) <= F::epsilon() * size * norm_inf(&y_next, n);
+        y = y_next;
+        if done {
+            return Ok(y);
+        }
+    }
+
+    Err(MatFunError::NoConvergence)
+}
+
+/// Principal logarithm by inverse scaling and squaring: square roots bring
+/// the matrix close to the identity, where the series
+/// `log(X) = 2 * atanh((X - I) * (X + I)^-1)` converges quickly.
+fn logm<F: TensorTypeFloat>(a: &[F], n: usize) -> Result<Vec<F>, MatFunError> {
+    let quarter = <F as NumCast>::from(0.25).unwrap();
+    let mut x = a.to_vec();
+    let mut roots = 0;
+
+    while dist_eye(&x, n) > quarter {
+        if roots == MAX_ITERS {
+            return Err(MatFunError::NoConvergence);
+        }
+        x = sqrtm(&x, n)?;
+        roots += 1;
+    }
+
+    let mut x_minus = x.clone();
+    let mut x_plus = x;
+    for i in 0..n {
+        x_minus[i * n + i] -= F::one();
+        x_plus[i * n + i] += F::one();
+    }
+    let z = matmul(&x_minus, &inv(&x_plus, n)?, n);
+    let z2 = matmul(&z, &z, n);
+
+    let mut term = z.clone();
+    let mut sum = z;
+    let mut converged = false;
+    for k in 1..MAX_ITERS {
+        term = matmul(&term, &z2, n);
+        let coef = Float::recip(<F as NumCast>::from(2 * k + 1).unwrap());
+        for (val, &t) in sum.iter_mut().zip(&term) {
+            *val += coef * t;
+        }
+        if coef * norm_inf(&term, n) <= F::epsilon() * norm_inf(&sum, n) {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return Err(MatFunError::NoConvergence);
+    }
+
+    // Undo the square roots, log(A) = 2^roots * log(X)
+    let scale = Float::powi(<F as NumCast>::from(2).unwrap(), roots as i32 + 1);
+    Ok(sum.into_iter().map(|val| val * scale).collect())
+}
+
+/// `A^p` by repeated squaring. Negative powers invert `A` first.
+fn matrix_power<F: TensorTypeFloat>(a: &[F], n: usize, p: i32) -> Result<Vec<F>, MatFunError> {
+    let mut base = match p < 0 {
+        true => inv(a, n)?,
+        false => a.to_vec(),
+    };
+    let mut power = identity(n);
+    let mut exp = p.unsigned_abs();
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            power = matmul(&power, &base, n);
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = matmul(&base, &base, n);
+        }
+    }
+    Ok(power)
+}
+
+impl<'a, F, S> TensorBase<'a, F, S>
+where
+    F: TensorTypeFloat,
+    S: TensorStorage<F>,
+{
+    /// Applies the matrix function `f` to every square matrix in the batch.
+    fn map_square<G>(&self, mut f: G) -> Result<Tensor<'static, F>, Error>
+    where
+        G: FnMut(&[F], usize) -> Result<Vec<F>, MatFunError>,
+    {
+        let (batch, n) = self.square_batch()?;
+        let data = self.data();
+        let mut data_f = Vec::with_capacity(data.len());
+
+        for b in 0..batch.iter().product() {
+            let val = f(&data[b * n * n..(b + 1) * n * n], n)
+                .map_err(|err| err.at(unravel(b, &batch)))?;
+            data_f.extend(val);
+        }
+
+        Tensor::from_shape(&self.shape, data_f)
+    }
+
+    /// Matrix exponential of a (batch of) square matrices, by scaling and
+    /// squaring with a Pade approximant. Matrices with NaN or infinite
+    /// elements give `Error::NoConvergence`.
+    pub fn expm(&self) -> Result<Tensor<'static, F>, Error> {
+        self.map_square(expm)
+    }
+
+    /// Principal matrix logarithm of a (batch of) square matrices. The
+    /// matrices must be nonsingular without eigenvalues on the negative real
+    /// axis, where the logarithm isn't real.
+    pub fn logm(&self) -> Result<Tensor<'static, F>, Error> {
+        self.map_square(logm)
+    }
+
+    /// Principal matrix square root of a (batch of) square matrices. The
+    /// matrices must be nonsingular without eigenvalues on the negative real
+    /// axis, where the square root isn't real.
+    pub fn sqrtm(&self) -> Result<Tensor<'static, F>, Error> {
+        self.map_square(sqrtm)
+    }
+
+    /// Raises a (batch of) square matrices to the integer power `p`. `p = 0`
+    /// gives the identity and negative powers require nonsingular matrices.
+    pub fn matrix_power(&self, p: i32) -> Result<Tensor<'static, F>, Error> {
+        self.map_square(|a, n| matrix_power(a, n, p))
+    }
+}
@@ -0,0 +1,200 @@
+use super::*;
+use num_traits::Float;
+use std::cmp::Ordering;
+
+impl<'a, U, S> TensorBase<'a, U, S>
+where
+    U: TensorType,
+    S: TensorStorage<U>,
+{
+    /// Applies `f` to every element, returning a new tensor of the same shape.
+    pub fn map<V, F>(&self, f: F) -> Tensor<'static, V>
+    where
+        V: TensorType,
+        F: FnMut(U) -> V,
+    {
+        Tensor {
+            strides: self.compute_strides(&self.shape),
+            shape: self.shape(),
+            offset: 0,
+            data: self.iter().map(f).collect(),
+            _u: PhantomData,
+            _s: PhantomData,
+        }
+    }
+
+    /// Combines the elements of both tensors with `f`, broadcasting them
+    /// against each other as `add` does.
+    pub fn zip_with<W, V, F>(
+        &self,
+        tensor_b: &TensorView<'_, W>,
+        mut f: F,
+    ) -> Result<Tensor<'static, V>, Error>
+    where
+        W: TensorType,
+        V: TensorType,
+        F: FnMut(U, W) -> V,
+    {
+        self.zip_bc(tensor_b, |_, a, b| Ok(f(a, b)))
+    }
+}
+
+impl<'a, U, S> TensorBase<'a, U, S>
+where
+    U: TensorType,
+    S: TensorStorage<U> + TensorStorageMut<U>,
+{
+    /// Replaces every element with `f` applied to it.
+    pub fn map_inplace<F>(&mut self, mut f: F)
+    where
+        F: FnMut(U) -> U,
+    {
+        for val in self.iter_mut() {
+            *val = f(*val);
+        }
+    }
+}
+
+impl<'a, U, S> TensorBase<'a, U, S>
+where
+    U: TensorTypeAbs,
+    S: TensorStorage<U>,
+{
+    /// Element-wise absolute value. Complex elements give their magnitude.
+    pub fn abs(&self) -> Tensor<'static, U::Real> {
+        self.map(U::magnitude)
+    }
+}
+
+/// NumPy style sign: -1, 0 or 1, with NaN kept as NaN.
+#[inline(always)]
+fn sign<F: TensorTypeFloat>(val: F) -> F {
+    match val.partial_cmp(&F::zero()) {
+        Some(Ordering::Less) => -F::one(),
+        Some(Ordering::Greater) => F::one(),
+        Some(Ordering::Equal) => F::zero(),
+        None => val,
+    }
+}
+
+fn check_clamp<F: TensorTypeFloat>(min: F, max: F) -> Result<(), Error> {
+    match min <= max {
+        true => Ok(()),
+        false => Err(Error::InvalidParam {
+            err_msg: format!("Invalid clamp range {}..={}", min, max),
+        }),
+    }
+}
+
+macro_rules! impl_unary_float_op {
+    ($($(#[$doc:meta])* ($name:ident, $name_inplace:ident, $op:expr);)*) => {
+        impl<'a, F, S> TensorBase<'a, F, S>
+        where
+            F: TensorTypeFloat,
+            S: TensorStorage<F>,
+        {
+            $(
+                $(#[$doc])*
+                #[inline(always)]
+                pub fn $name(&self) -> Tensor<'static, F> {
+                    self.map($op)
+                }
+            )*
+        }
+
+        impl<'a, F, S> TensorBase<'a, F, S>
+        where
+            F: TensorTypeFloat,
+            S: TensorStorage<F> + TensorStorageMut<F>,
+        {
+            $(
+                #[doc = concat!("In-place version of [`Self::", stringify!($name), "`].")]
+                #[inline(always)]
+                pub fn $name_inplace(&mut self) {
+                    self.map_inplace($op)
+                }
+            )*
+        }
+    };
+}
+
+impl_unary_float_op! {
+    /// Element-wise `e^x`.
+    (exp, exp_, Float::exp);
+    /// Element-wise natural logarithm.
+    (ln, ln_, Float::ln);
+    /// Element-wise base 2 logarithm.
+    (log2, log2_, Float::log2);
+    /// Element-wise base 10 logarithm.
+    (log10, log10_, Float::log10);
+    /// Element-wise square root.
+    (sqrt, sqrt_, Float::sqrt);
+    /// Element-wise sine, in radians.
+    (sin, sin_, Float::sin);
+    /// Element-wise cosine, in radians.
+    (cos, cos_, Float::cos);
+    /// Element-wise tangent, in radians.
+    (tan, tan_, Float::tan);
+    /// Element-wise hyperbolic tangent.
+    (tanh, tanh_, Float::tanh);
+    /// Element-wise largest integer less than or equal to `x`.
+    (floor, floor_, Float::floor);
+    /// Element-wise smallest integer greater than or equal to `x`.
+    (ceil, ceil_, Float::ceil);
+    /// Element-wise rounding, half-way cases away from zero (NumPy rounds to even).
+    (round, round_, Float::round);
+    /// Element-wise `1 / x`.
+    (recip, recip_, Float::recip);
+    /// Element-wise sign as -1, 0 or 1. NaN stays NaN.
+    (sign, sign_, sign);
+}
+
+impl<'a, F, S> TensorBase<'a, F, S>
+where
+    F: TensorTypeFloat,
+    S: TensorStorage<F>,
+{
+    /// Raises every element to the integer power `n`.
+    pub fn powi(&self, n: i32) -> Tensor<'static, F> {
+        self.map(|val| Float::powi(val, n))
+    }
+
+    /// Raises every element to the power `n`.
+    pub fn powf(&self, n: F) -> Tensor<'static, F> {
+        self.map(|val| Float::powf(val, n))
+    }
+
+    /// Limits every element to `min..=max`. NaN elements are kept.
+    pub fn clamp(&self, min: F, max: F) -> Result<Tensor<'static, F>, Error> {
+        check_clamp(min, max)?;
+        Ok(self.map(|val| num_traits::clamp(val, min, max)))
+    }
+}
+
+impl<'a, F, S> TensorBase<'a, F, S>
+where
+    F: TensorTypeFloat,
+    S: TensorStorage<F> + TensorStorageMut<F>,
+{
+    /// In-place version of [`Self::abs`].
+    pub fn abs_(&mut self) {
+        self.map_inplace(Float::abs)
+    }
+
+    /// In-place version of [`Self::powi`].
+    pub fn powi_(&mut self, n: i32) {
+        self.map_inplace(|val| Float::powi(val, n))
+    }
+
+    /// In-place version of [`Self::powf`].
+    pub fn powf_(&mut self, n: F) {
+        self.map_inplace(|val| Float::powf(val, n))
+    }
+
+    /// In-place version of [`Self::clamp`].
+    pub fn clamp_(&mut self, min: F, max: F) -> Result<(), Error> {
+        check_clamp(min, max)?;
+        self.map_inplace(|val| num_traits::clamp(val, min, max));
+        Ok(())
+    }
+}
@@ -0,0 +1,631 @@
+use super::*;
+use num_traits::Float;
+use std::cmp::Ordering;
+
+/// NaN is the only value that compares unequal to itself, which keeps the
+/// check generic over integer and floating point element types.
+#[inline(always)]
+#[allow(clippy::eq_op)]
+fn is_nan<U: PartialEq>(val: U) -> bool {
+    val != val
+}
+
+impl<'a, U, S> TensorBase<'a, U, S>
+where
+    U: TensorType,
+    S: TensorStorage<U>,
+{
+    /// Computes the shape of the reduced tensor along with the strides used to
+    /// map an input index to its output element. Reduced axes get a zero
+    /// stride so that all of their elements land on the same output element.
+    pub(crate) fn reduce_shape(
+        &self,
+        axes: &[usize],
+        keepdims: bool,
+    ) -> Result<(Vec<usize>, Vec<usize>), Error> {
+        let ndim = self.ndim();
+        let mut reduced = vec![false; ndim];
+
+        for &axis in axes {
+            if axis >= ndim {
+                return Err(Error::InvalidAxis { axis, ndim });
+            }
+            if reduced[axis] {
+                return Err(Error::InvalidParam {
+                    err_msg: format!("Axis {} is repeated in {:?}", axis, axes),
+                });
+            }
+            reduced[axis] = true;
+        }
+
+        let shape_kd: Vec<usize> = (0..ndim)
+            .map(|dim| if reduced[dim] { 1 } else { self.shape[dim] })
+            .collect();
+        // Shapes with a zero dimension have no strides and no elements to map
+        let mut strides_map = self.compute_strides(&shape_kd);
+        strides_map.resize(ndim, 0);
+        for dim in 0..ndim {
+            if reduced[dim] {
+                strides_map[dim] = 0;
+            }
+        }
+
+        let shape_r = match keepdims {
+            true => shape_kd,
+            false => (0..ndim)
+                .filter(|&dim| !reduced[dim])
+                .map(|dim| self.shape[dim])
+                .collect(),
+        };
+
+        Ok((shape_r, strides_map))
+    }
+
+    pub(crate) fn reduce_impl<F>(
+        &self,
+        axes: &[usize],
+        keepdims: bool,
+        init: U,
+        mut op: F,
+    ) -> Result<Tensor<'static, U>, Error>
+    where
+        F: FnMut(U, U) -> U,
+    {
+        let (shape_r, strides_map) = self.reduce_shape(axes, keepdims)?;
+        let mut data_r = vec![init; shape_r.iter().product()];
+
+        for index in self.shape_indexes(&self.shape) {
+            let mut offset = self.offset;
+            let mut offset_r = 0;
+            for (dim, &idx) in index.iter().enumerate() {
+                offset += idx * self.strides[dim];
+                offset_r += idx * strides_map[dim];
+            }
+            data_r[offset_r] = op(data_r[offset_r], self.data[offset]);
+        }
+
+        Ok(Tensor {
+            strides: self.compute_strides(&shape_r),
+            shape: shape_r,
+            offset: 0,
+            data: data_r,
+            _u: PhantomData,
+            _s: PhantomData,
+        })
+    }
+
+    /// Like `reduce_impl`, but every output element starts from the first
+    /// element reduced into it instead of an identity, so reducing an empty
+    /// axis is an error.
+    pub(crate) fn reduce_first_impl<F>(
+        &self,
+        axes: &[usize],
+        keepdims: bool,
+        mut op: F,
+    ) -> Result<Tensor<'static, U>, Error>
+    where
+        F: FnMut(U, U) -> U,
+    {
+        let (shape_r, strides_map) = self.reduce_shape(axes, keepdims)?;
+        let nelems_r = shape_r.iter().product();
+        if nelems_r > 0 {
+            if let Some(&axis) = axes.iter().find(|&&axis| self.shape[axis] == 0) {
+                return Err(Error::InvalidParam {
+                    err_msg: format!("Axis {} of shape {:?} is empty", axis, self.shape),
+                });
+            }
+        }
+        let mut data_r: Vec<Option<U>> = vec![None; nelems_r];
+
+        for index in self.shape_indexes(&self.shape) {
+            let mut offset = self.offset;
+            let mut offset_r = 0;
+            for (dim, &idx) in index.iter().enumerate() {
+                offset += idx * self.strides[dim];
+                offset_r += idx * strides_map[dim];
+            }
+            let val = self.data[offset];
+            data_r[offset_r] = Some(match data_r[offset_r] {
+                Some(acc) => op(acc, val),
+                None => val,
+            });
+        }
+
+        Ok(Tensor {
+            strides: self.compute_strides(&shape_r),
+            shape: shape_r,
+            offset: 0,
+            data: data_r.into_iter().flatten().collect(),
+            _u: PhantomData,
+            _s: PhantomData,
+        })
+    }
+}
+
+impl<'a, U, S> TensorBase<'a, U, S>
+where
+    U: TensorTypeNumeric,
+    S: TensorStorage<U>,
+{
+    pub fn sum(&self) -> U {
+        let mut sum = U::default();
+
+        for val in self.iter() {
+            sum += val;
+        }
+
+        sum
+    }
+
+    pub fn prod(&self) -> U {
+        let mut prod = U::one();
+
+        for val in self.iter() {
+            prod *= val;
+        }
+
+        prod
+    }
+
+    /// Sum over the given axes. With `keepdims` the reduced axes are retained
+    /// with size 1, so the result broadcasts against the input.
+    pub fn sum_axis(&self, axes: &[usize], keepdims: bool) -> Result<Tensor<'static, U>, Error> {
+        self.reduce_impl(axes, keepdims, U::default(), |acc, val| acc + val)
+    }
+
+    pub fn prod_axis(&self, axes: &[usize], keepdims: bool) -> Result<Tensor<'static, U>, Error> {
+        self.reduce_impl(axes, keepdims, U::one(), |acc, val| acc * val)
+    }
+}
+
+impl<'a, U, S> TensorBase<'a, U, S>
+where
+    U: TensorTypeOrdered,
+    S: TensorStorage<U>,
+{
+    /// Maximum over the given axes. NaN propagates, so a NaN anywhere in the
+    /// reduced elements gives NaN (as in NumPy).
+    pub fn max_axis(&self, axes: &[usize], keepdims: bool) -> Result<Tensor<'static, U>, Error> {
+        self.reduce_first_impl(axes, keepdims, |acc, val| {
+            if is_nan(acc) {
+                acc
+            } else if is_nan(val) || val > acc {
+                val
+            } else {
+                acc
+            }
+        })
+    }
+
+    /// Minimum over the given axes. NaN propagates, so a NaN anywhere in the
+    /// reduced elements gives NaN (as in NumPy).
+    pub fn min_axis(&self, axes: &[usize], keepdims: bool) -> Result<Tensor<'static, U>, Error> {
+        self.reduce_first_impl(axes, keepdims, |acc, val| {
+            if is_nan(acc) {
+                acc
+            } else if is_nan(val) || val < acc {
+                val
+            } else {
+                acc
+            }
+        })
+    }
+
+    /// Lists the 1-D lanes along `axis` in row-major order of the remaining
+    /// axes. Each lane is given by its index (with `axis` set to 0) and the
+    /// offset of its first element.
+    fn lanes(&self, axis: usize) -> Result<Vec<(Vec<usize>, usize)>, Error> {
+        if axis >= self.ndim() {
+            return Err(Error::InvalidAxis {
+                axis,
+                ndim: self.ndim(),
+            });
+        }
+
+        let mut shape_outer = self.shape();
+        shape_outer[axis] = 1;
+
+        let lanes = self
+            .shape_indexes(&shape_outer)
+            .map(|index| {
+                let offset = self.offset
+                    + index
+                        .iter()
+                        .zip(&self.strides)
+                        .map(|(&idx, &stride)| idx * stride)
+                        .sum::<usize>();
+                (index, offset)
+            })
+            .collect();
+
+        Ok(lanes)
+    }
+
+    fn arg_impl<F>(&self, axis: usize, mut pick: F) -> Result<Tensor<'static, u64>, Error>
+    where
+        F: FnMut(U, U) -> bool,
+    {
+        let lanes = self.lanes(axis)?;
+        let len = self.shape[axis];
+        if len == 0 {
+            return Err(Error::InvalidParam {
+                err_msg: format!("Axis {} of shape {:?} is empty", axis, self.shape),
+            });
+        }
+
+        let stride = self.strides[axis];
+        let mut data_r = Vec::new();
+
+        for (_, base) in lanes {
+            let mut best = (0, self.data[base]);
+            for i in 1..len {
+                // NaN propagates: The first NaN in the lane is always picked
+                if is_nan(best.1) {
+                    break;
+                }
+                let val = self.data[base + i * stride];
+                if is_nan(val) || pick(val, best.1) {
+                    best = (i, val);
+                }
+            }
+            data_r.push(best.0 as u64);
+        }
+
+        let mut shape_r = self.shape();
+        shape_r.remove(axis);
+
+        Ok(Tensor {
+            strides: self.compute_strides(&shape_r),
+            shape: shape_r,
+            offset: 0,
+            data: data_r,
+            _u: PhantomData,
+            _s: PhantomData,
+        })
+    }
+
+    /// Index of the largest element along `axis`. The first occurrence wins on
+    /// ties, and the index of the first NaN is returned if the lane has any
+    /// (as in NumPy).
+    pub fn argmax(&self, axis: usize) -> Result<Tensor<'static, u64>, Error> {
+        self.arg_impl(axis, |val, best| val > best)
+    }
+
+    /// Index of the smallest element along `axis`. The first occurrence wins on
+    /// ties, and the index of the first NaN is returned if the lane has any
+    /// (as in NumPy).
+    pub fn argmin(&self, axis: usize) -> Result<Tensor<'static, u64>, Error> {
+        self.arg_impl(axis, |val, best| val < best)
+    }
+
+    /// The `k` largest (or smallest, if `largest` is false) elements along
+    /// `axis`, returned as a `(values, indices)` pair in sorted order. The size
+    /// of `axis` in both results is `k`. Equal elements keep their original
+    /// order, and NaN is ordered above every other value.
+    pub fn topk(
+        &self,
+        k: usize,
+        axis: usize,
+        largest: bool,
+    ) -> Result<(Tensor<'static, U>, Tensor<'static, u64>), Error> {
+        let lanes = self.lanes(axis)?;
+        let len = self.shape[axis];
+        if k > len {
+            return Err(Error::InvalidParam {
+                err_msg: format!("k ({}) exceeds the size of axis {} ({})", k, axis, len),
+            });
+        }
+
+        // Ascending order with NaN above every other value
+        let order = |a: U, b: U| match (is_nan(a), is_nan(b)) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        };
+
+        let stride = self.strides[axis];
+        let mut shape_r = self.shape();
+        shape_r[axis] = k;
+        let strides_r = self.compute_strides(&shape_r);
+        let nelems_r = shape_r.iter().product();
+        let mut values = vec![U::default(); nelems_r];
+        let mut indices = vec![0u64; nelems_r];
+
+        for (index, base) in lanes {
+            let mut lane: Vec<(usize, U)> = (0..len)
+                .map(|i| (i, self.data[base + i * stride]))
+                .collect();
+
+            match largest {
+                true => lane.sort_by(|a, b| order(b.1, a.1)),
+                false => lane.sort_by(|a, b| order(a.1, b.1)),
+            }
+
+            let base_r: usize = index
+                .iter()
+                .zip(&strides_r)
+                .map(|(&idx, &stride)| idx * stride)
+                .sum();
+
+            for (j, &(i, val)) in lane.iter().take(k).enumerate() {
+                values[base_r + j * strides_r[axis]] = val;
+                indices[base_r + j * strides_r[axis]] = i as u64;
+            }
+        }
+
+        let values = Tensor {
+            shape: shape_r.clone(),
+            strides: strides_r.clone(),
+            offset: 0,
+            data: values,
+            _u: PhantomData,
+            _s: PhantomData,
+        };
+
+        let indices = Tensor {
+            shape: shape_r,
+            strides: strides_r,
+            offset: 0,
+            data: indices,
+            _u: PhantomData,
+            _s: PhantomData,
+        };
+
+        Ok((values, indices))
+    }
+}
+
+/// Kahan (compensated) summation. The rounding error of every addition is
+/// fed back into the next one, so long f32 sums don't drift.
+#[derive(Clone, Copy)]
+struct KahanSum<F> {
+    sum: F,
+    comp: F,
+}
+
+impl<F: TensorTypeFloat> KahanSum<F> {
+    fn new() -> Self {
+        KahanSum {
+            sum: F::default(),
+            comp: F::default(),
+        }
+    }
+
+    #[inline(always)]
+    fn add(&mut self, val: F) {
+        let val = val - self.comp;
+        let sum = self.sum + val;
+        // The compensation is meaningless (NaN) once the sum overflows
+        self.comp = match Float::is_finite(sum) {
+            true => (sum - self.sum) - val,
+            false => F::default(),
+        };
+        self.sum = sum;
+    }
+
+    #[inline(always)]
+    fn value(&self) -> F {
+        self.sum
+    }
+}
+
+impl<'a, F, S> TensorBase<'a, F, S>
+where
+    F: TensorTypeFloat + 'static,
+    S: TensorStorage<F>,
+{
+    #[inline(always)]
+    fn count(&self, axes: &[usize]) -> F {
+        let count: usize = axes.iter().map(|&axis| self.shape[axis]).product();
+        F::from(count).unwrap()
+    }
+
+    #[inline(always)]
+    fn all_axes(&self) -> Vec<usize> {
+        (0..self.ndim()).collect()
+    }
+
+    /// Compensated sum of `map(value, offset_r)` over the given axes, where
+    /// `offset_r` is the offset of the output element the value reduces into.
+    fn sum_compensated_impl<M>(
+        &self,
+        axes: &[usize],
+        keepdims: bool,
+        mut map: M,
+    ) -> Result<Tensor<'static, F>, Error>
+    where
+        M: FnMut(F, usize) -> F,
+    {
+        let (shape_r, strides_map) = self.reduce_shape(axes, keepdims)?;
+        let mut sums = vec![KahanSum::new(); shape_r.iter().product()];
+
+        for index in self.shape_indexes(&self.shape) {
+            let mut offset = self.offset;
+            let mut offset_r = 0;
+            for (dim, &idx) in index.iter().enumerate() {
+                offset += idx * self.strides[dim];
+                offset_r += idx * strides_map[dim];
+            }
+            sums[offset_r].add(map(self.data[offset], offset_r));
+        }
+
+        Ok(Tensor {
+            strides: self.compute_strides(&shape_r),
+            shape: shape_r,
+            offset: 0,
+            data: sums.iter().map(|sum| sum.value()).collect(),
+            _u: PhantomData,
+            _s: PhantomData,
+        })
+    }
+
+    /// Arithmetic mean of all elements, using compensated summation.
+    pub fn mean(&self) -> F {
+        self.mean_axis(&self.all_axes(), false).unwrap().data[0]
+    }
+
+    /// Arithmetic mean over the given axes, using compensated summation. With
+    /// `keepdims` the reduced axes are retained with size 1, so the result
+    /// broadcasts against the input.
+    pub fn mean_axis(&self, axes: &[usize], keepdims: bool) -> Result<Tensor<'static, F>, Error> {
+        let mut tensor = self.sum_compensated_impl(axes, keepdims, |val, _| val)?;
+        let count = self.count(axes);
+
+        for val in tensor.data.iter_mut() {
+            *val /= count;
+        }
+
+        Ok(tensor)
+    }
+
+    /// Variance of all elements with `ddof` delta degrees of freedom (the
+    /// divisor is `N - ddof`). NaN is returned if `N <= ddof`.
+    pub fn var(&self, ddof: usize) -> F {
+        self.var_axis(&self.all_axes(), ddof, false).unwrap().data[0]
+    }
+
+    /// Variance over the given axes with `ddof` delta degrees of freedom. Uses
+    /// the two-pass algorithm with compensated summation.
+    pub fn var_axis(
+        &self,
+        axes: &[usize],
+        ddof: usize,
+        keepdims: bool,
+    ) -> Result<Tensor<'static, F>, Error> {
+        let mean = self.mean_axis(axes, true)?;
+        let mut tensor = self.sum_compensated_impl(axes, keepdims, |val, offset_r| {
+            let diff = val - mean.data[offset_r];
+            diff * diff
+        })?;
+
+        let count = self.count(axes);
+        let ddof = F::from(ddof).unwrap();
+        let div = match count > ddof {
+            true => count - ddof,
+            false => F::nan(),
+        };
+
+        for val in tensor.data.iter_mut() {
+            *val /= div;
+        }
+
+        Ok(tensor)
+    }
+
+    /// Standard deviation of all elements with `ddof` delta degrees of freedom.
+    pub fn std(&self, ddof: usize) -> F {
+        Float::sqrt(self.var(ddof))
+    }
+
+    /// Standard deviation over the given axes with `ddof` delta degrees of
+    /// freedom.
+    pub fn std_axis(
+        &self,
+        axes: &[usize],
+        ddof: usize,
+        keepdims: bool,
+    ) -> Result<Tensor<'static, F>, Error> {
+        let mut tensor = self.var_axis(axes, ddof, keepdims)?;
+
+        for val in tensor.data.iter_mut() {
+            *val = Float::sqrt(*val);
+        }
+
+        Ok(tensor)
+    }
+
+    /// `log(sum(exp(x)))` of all elements, computed without overflow by
+    /// shifting with the maximum.
+    pub fn logsumexp(&self) -> F {
+        self.logsumexp_axis(&self.all_axes(), false).unwrap().data[0]
+    }
+
+    /// `log(sum(exp(x)))` over the given axes, computed without overflow by
+    /// shifting with the maximum of each reduced lane.
+    pub fn logsumexp_axis(
+        &self,
+        axes: &[usize],
+        keepdims: bool,
+    ) -> Result<Tensor<'static, F>, Error> {
+        let max = self.max_axis(axes, true)?;
+        let mut tensor = self.sum_compensated_impl(axes, keepdims, |val, offset_r| {
+            let shift = max.data[offset_r];
+            match Float::is_finite(shift) {
+                true => Float::exp(val - shift),
+                false => val,
+            }
+        })?;
+
+        for (val, &shift) in tensor.data.iter_mut().zip(&max.data) {
+            *val = match Float::is_finite(shift) {
+                true => shift + Float::ln(*val),
+                false => shift,
+            };
+        }
+
+        Ok(tensor)
+    }
+
+    /// Norm of all elements, treating the tensor as a flat vector. `Norm::Fro`
+    /// and `Norm::L2` give the same result.
+    pub fn norm(&self, ord: Norm) -> F {
+        let ord = match ord {
+            Norm::Fro => Norm::L2,
+            _ => ord,
+        };
+        self.norm_axis(ord, &self.all_axes(), false).unwrap().data[0]
+    }
+
+    /// Vector norm over the given axes. `Norm::Fro` is the matrix Frobenius
+    /// norm and requires exactly two axes.
+    pub fn norm_axis(
+        &self,
+        ord: Norm,
+        axes: &[usize],
+        keepdims: bool,
+    ) -> Result<Tensor<'static, F>, Error> {
+        match ord {
+            Norm::L1 => self.sum_compensated_impl(axes, keepdims, |val, _| Float::abs(val)),
+            Norm::L2 | Norm::Fro => {
+                if ord == Norm::Fro && axes.len() != 2 {
+                    return Err(Error::InvalidParam {
+                        err_msg: format!("Frobenius norm expects two axes, got {:?}", axes),
+                    });
+                }
+
+                let mut tensor = self.sum_compensated_impl(axes, keepdims, |val, _| val * val)?;
+                for val in tensor.data.iter_mut() {
+                    *val = Float::sqrt(*val);
+                }
+                Ok(tensor)
+            }
+            Norm::Linf => {
+                let (shape_r, strides_map) = self.reduce_shape(axes, keepdims)?;
+                let mut data_r = vec![F::default(); shape_r.iter().product()];
+
+                for index in self.shape_indexes(&self.shape) {
+                    let mut offset = self.offset;
+                    let mut offset_r = 0;
+                    for (dim, &idx) in index.iter().enumerate() {
+                        offset += idx * self.strides[dim];
+                        offset_r += idx * strides_map[dim];
+                    }
+                    let val = Float::abs(self.data[offset]);
+                    if Float::is_nan(val) || val > data_r[offset_r] {
+                        data_r[offset_r] = val;
+                    }
+                }
+
+                Ok(Tensor {
+                    strides: self.compute_strides(&shape_r),
+                    shape: shape_r,
+                    offset: 0,
+                    data: data_r,
+                    _u: PhantomData,
+                    _s: PhantomData,
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,290 @@
+use super::*;
+use std::ops::{Bound, RangeBounds};
+use std::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+/// Builds a `SliceInfo` from a comma separated list of indices, ranges and
+/// `NewAxis`, where a range may be followed by `;step`.
+///
+/// `s![1, ..;2, -3.., NewAxis]` selects row 1, every second column of the
+/// second axis, the last three elements of the third axis and appends a new
+/// axis of size 1.
+#[macro_export]
+macro_rules! s {
+    (@parse [$($elems:expr),*]) => {
+        $crate::tensor::SliceInfo::from(vec![$($elems),*])
+    };
+    (@parse [$($elems:expr),*] $r:expr ; $step:expr) => {
+        $crate::s!(@parse [$($elems,)* $crate::tensor::SliceElem::from($r).step_by($step)])
+    };
+    (@parse [$($elems:expr),*] $r:expr ; $step:expr , $($rest:tt)*) => {
+        $crate::s!(@parse [$($elems,)* $crate::tensor::SliceElem::from($r).step_by($step)] $($rest)*)
+    };
+    (@parse [$($elems:expr),*] $r:expr) => {
+        $crate::s!(@parse [$($elems,)* $crate::tensor::SliceElem::from($r)])
+    };
+    (@parse [$($elems:expr),*] $r:expr , $($rest:tt)*) => {
+        $crate::s!(@parse [$($elems,)* $crate::tensor::SliceElem::from($r)] $($rest)*)
+    };
+    ($($t:tt)*) => {
+        $crate::s!(@parse [] $($t)*)
+    };
+}
+
+impl SliceElem {
+    fn from_bounds<R: RangeBounds<isize>>(range: R) -> Self {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+
+        // An inclusive end of -1 runs up to the last element
+        let end = match range.end_bound() {
+            Bound::Included(&-1) => None,
+            Bound::Included(&end) => Some(end + 1),
+            Bound::Excluded(&end) => Some(end),
+            Bound::Unbounded => None,
+        };
+
+        SliceElem::Range {
+            start,
+            end,
+            step: 1,
+        }
+    }
+
+    /// Sets the step of a range. Indices and new axes are left unchanged.
+    pub fn step_by(self, step: usize) -> Self {
+        match self {
+            SliceElem::Range { start, end, .. } => SliceElem::Range { start, end, step },
+            _ => self,
+        }
+    }
+}
+
+impl From<NewAxis> for SliceElem {
+    fn from(_: NewAxis) -> Self {
+        SliceElem::NewAxis
+    }
+}
+
+impl From<RangeFull> for SliceElem {
+    fn from(range: RangeFull) -> Self {
+        SliceElem::from_bounds::<RangeFull>(range)
+    }
+}
+
+macro_rules! impl_slice_elem_from {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for SliceElem {
+                fn from(index: $t) -> Self {
+                    SliceElem::Index(index as isize)
+                }
+            }
+
+            impl From<Range<$t>> for SliceElem {
+                fn from(range: Range<$t>) -> Self {
+                    SliceElem::from_bounds(range.start as isize..range.end as isize)
+                }
+            }
+
+            impl From<RangeInclusive<$t>> for SliceElem {
+                fn from(range: RangeInclusive<$t>) -> Self {
+                    SliceElem::from_bounds(*range.start() as isize..=*range.end() as isize)
+                }
+            }
+
+            impl From<RangeFrom<$t>> for SliceElem {
+                fn from(range: RangeFrom<$t>) -> Self {
+                    SliceElem::from_bounds(range.start as isize..)
+                }
+            }
+
+            impl From<RangeTo<$t>> for SliceElem {
+                fn from(range: RangeTo<$t>) -> Self {
+                    SliceElem::from_bounds(..range.end as isize)
+                }
+            }
+
+            impl From<RangeToInclusive<$t>> for SliceElem {
+                fn from(range: RangeToInclusive<$t>) -> Self {
+                    SliceElem::from_bounds(..=range.end as isize)
+                }
+            }
+        )*
+    };
+}
+
+impl_slice_elem_from!(isize, usize, i32);
+
+impl SliceInfo {
+    pub fn new() -> Self {
+        SliceInfo { elems: Vec::new() }
+    }
+
+    /// Selects a single index, removing the axis. Negative indices count
+    /// from the end of the axis.
+    pub fn index(mut self, index: isize) -> Self {
+        self.elems.push(SliceElem::Index(index));
+        self
+    }
+
+    /// Selects a range of the axis. Negative bounds count from the end and
+    /// bounds beyond the axis are clipped, as in NumPy.
+    pub fn range<R: RangeBounds<isize>>(mut self, range: R) -> Self {
+        self.elems.push(SliceElem::from_bounds(range));
+        self
+    }
+
+    pub fn range_step<R: RangeBounds<isize>>(mut self, range: R, step: usize) -> Self {
+        self.elems.push(SliceElem::from_bounds(range).step_by(step));
+        self
+    }
+
+    /// Selects the whole axis.
+    pub fn full(self) -> Self {
+        self.range(..)
+    }
+
+    /// Inserts a new axis of size 1.
+    pub fn new_axis(mut self) -> Self {
+        self.elems.push(SliceElem::NewAxis);
+        self
+    }
+
+    pub fn elems(&self) -> &[SliceElem] {
+        &self.elems
+    }
+}
+
+impl From<Vec<SliceElem>> for SliceInfo {
+    fn from(elems: Vec<SliceElem>) -> Self {
+        SliceInfo { elems }
+    }
+}
+
+impl<'a, U, S> TensorBase<'a, U, S>
+where
+    U: TensorType,
+    S: TensorStorage<U>,
+{
+    fn slice_info_impl(&self, info: &SliceInfo) -> Result<(Vec<usize>, Vec<usize>, usize), Error> {
+        let nsliced = info
+            .elems
+            .iter()
+            .filter(|elem| **elem != SliceElem::NewAxis)
+            .count();
+
+        if nsliced > self.ndim() {
+            return Err(Error::DimensionMismatch {
+                tensor_dim: self.ndim(),
+                dim: nsliced,
+            });
+        }
+
+        let mut shape = Vec::with_capacity(self.ndim() + info.elems.len() - nsliced);
+        let mut strides = Vec::with_capacity(shape.capacity());
+        let mut new_axes = Vec::new();
+        let mut offset = self.offset;
+        let mut axis = 0;
+
+        for elem in &info.elems {
+            match *elem {
+                SliceElem::Index(index) => {
+                    let len = self.shape[axis] as isize;
+                    let index_r = if index < 0 { index + len } else { index };
+
+                    if index_r < 0 || index_r >= len {
+                        return Err(Error::InvalidParam {
+                            err_msg: format!(
+                                "Index {} is out of range for axis {} of size {}",
+                                index, axis, len
+                            ),
+                        });
+                    }
+
+                    offset += index_r as usize * self.strides[axis];
+                    axis += 1;
+                }
+                SliceElem::Range { start, end, step } => {
+                    if step == 0 {
+                        return Err(Error::InvalidParam {
+                            err_msg: "Slice step must be greater than 0".to_string(),
+                        });
+                    }
+
+                    let len = self.shape[axis] as isize;
+                    let clip = |bound: isize| {
+                        let bound = if bound < 0 { bound + len } else { bound };
+                        bound.clamp(0, len) as usize
+                    };
+                    let start = clip(start);
+                    let end = clip(end.unwrap_or(len));
+
+                    shape.push(end.saturating_sub(start).div_ceil(step));
+                    strides.push(self.strides[axis] * step);
+
+                    // An empty range has no element to point at
+                    if start < end {
+                        offset += start * self.strides[axis];
+                    }
+                    axis += 1;
+                }
+                SliceElem::NewAxis => {
+                    new_axes.push(shape.len());
+                    shape.push(1);
+                    strides.push(0);
+                }
+            }
+        }
+
+        // Axes without a slice element are kept whole
+        shape.extend_from_slice(&self.shape[axis..]);
+        strides.extend_from_slice(&self.strides[axis..]);
+
+        // Give new axes the stride they'd have in a contiguous layout
+        for &new_axis in new_axes.iter().rev() {
+            strides[new_axis] = match new_axis + 1 < shape.len() {
+                true => shape[new_axis + 1] * strides[new_axis + 1],
+                false => 1,
+            };
+        }
+
+        Ok((shape, strides, offset))
+    }
+
+    /// Returns a strided view selected by `info`, usually built with the `s!`
+    /// macro. Axes not covered by `info` are kept whole.
+    pub fn slice_info(&'a self, info: &SliceInfo) -> Result<TensorView<'a, U>, Error> {
+        let (shape, strides, offset) = self.slice_info_impl(info)?;
+
+        Ok(TensorView {
+            shape,
+            strides,
+            offset,
+            data: self.data.as_ref(),
+            _u: PhantomData,
+            _s: PhantomData,
+        })
+    }
+}
+
+impl<'a, U, S> TensorBase<'a, U, S>
+where
+    U: TensorType,
+    S: TensorStorage<U> + TensorStorageMut<U>,
+{
+    pub fn slice_info_mut(&'a mut self, info: &SliceInfo) -> Result<TensorViewMut<'a, U>, Error> {
+        let (shape, strides, offset) = self.slice_info_impl(info)?;
+
+        Ok(TensorViewMut {
+            shape,
+            strides,
+            offset,
+            data: self.data.as_mut(),
+            _u: PhantomData,
+            _s: PhantomData,
+        })
+    }
+}
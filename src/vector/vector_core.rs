@@ -1,4 +1,6 @@
-use crate::tensor::{Error, Tensor, TensorEq, TensorTypeNumeric};
+use crate::tensor::{
+    Error, Tensor, TensorEq, TensorTypeFloat, TensorTypeNumeric, TensorTypeOrdered,
+};
 use std::fmt::Debug;
 
 #[derive(Debug)]
@@ -15,13 +17,31 @@ where
         Ok(Vector { tensor: vector })
     }
 
+    pub fn ones(nelems: usize) -> Result<Vector<'a, U>, Error> {
+        let vector = Tensor::ones(&[nelems, 1])?;
+        Ok(Vector { tensor: vector })
+    }
+
+    pub fn full(nelems: usize, value: U) -> Result<Vector<'a, U>, Error> {
+        let vector = Tensor::full(&[nelems, 1], value)?;
+        Ok(Vector { tensor: vector })
+    }
+
+    pub fn from_fn<F>(nelems: usize, mut f: F) -> Result<Vector<'a, U>, Error>
+    where
+        F: FnMut(usize) -> U,
+    {
+        let vector = Tensor::from_fn(&[nelems, 1], |index| f(index[0]))?;
+        Ok(Vector { tensor: vector })
+    }
+
     pub fn from_vec(data: Vec<U>) -> Result<Vector<'a, U>, Error> {
-        let vector = Tensor::from_shape(&[data.len(), 1], &data)?;
+        let vector = Tensor::from_shape(&[data.len(), 1], data)?;
         Ok(Vector { tensor: vector })
     }
 
     pub fn from_arr<const N: usize>(data: &[U; N]) -> Result<Vector<'a, U>, Error> {
-        let vector = Tensor::from_shape(&[N, 1], &data.to_vec())?;
+        let vector = Tensor::from_shape(&[N, 1], data.to_vec())?;
         Ok(Vector { tensor: vector })
     }
 
@@ -36,6 +56,28 @@ where
     }
 }
 
+impl<'a, U> Vector<'a, U>
+where
+    U: TensorTypeOrdered + Default + Debug,
+{
+    pub fn arange(start: U, stop: U, step: U) -> Result<Vector<'a, U>, Error> {
+        Self::from_vec(Tensor::arange(start, stop, step)?.data())
+    }
+}
+
+impl<'a, F> Vector<'a, F>
+where
+    F: TensorTypeFloat,
+{
+    pub fn linspace(start: F, stop: F, num: usize) -> Result<Vector<'a, F>, Error> {
+        Self::from_vec(Tensor::linspace(start, stop, num)?.data())
+    }
+
+    pub fn logspace(start: F, stop: F, num: usize, base: F) -> Result<Vector<'a, F>, Error> {
+        Self::from_vec(Tensor::logspace(start, stop, num, base)?.data())
+    }
+}
+
 impl<'a, U, T> PartialEq<Vec<T>> for Vector<'a, U>
 where
     U: TensorTypeNumeric + PartialEq + Debug,
@@ -6,6 +6,24 @@ fn from_zeros() {
     assert_eq!(matrix, vec![vec![0, 0, 0], vec![0, 0, 0]]);
 }
 
+#[test]
+fn ones_full_eye() {
+    let matrix = Matrix::<i32>::ones(2, 2).unwrap();
+    assert_eq!(matrix, vec![vec![1, 1], vec![1, 1]]);
+
+    let matrix = Matrix::full(1, 3, 7).unwrap();
+    assert_eq!(matrix, vec![vec![7, 7, 7]]);
+
+    let matrix = Matrix::<f64>::eye(2, 3).unwrap();
+    assert_eq!(matrix, vec![vec![1.0, 0.0, 0.0], vec![0.0, 1.0, 0.0]]);
+}
+
+#[test]
+fn from_fn() {
+    let matrix = Matrix::from_fn(2, 3, |row, col| (row * 3 + col) as u32).unwrap();
+    assert_eq!(matrix, vec![vec![0, 1, 2], vec![3, 4, 5]]);
+}
+
 #[test]
 fn from_vec() {
     let matrix = Matrix::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
@@ -0,0 +1,112 @@
+use rs_math::matrix::Matrix;
+use rs_math::tensor::{Error, QrMode, Triangle};
+
+#[test]
+fn lu_solve() {
+    let matrix_a = Matrix::from_vec(vec![vec![0.0, 2.0], vec![3.0, 1.0]]).unwrap();
+
+    let (p, l, u) = matrix_a.lu().unwrap();
+    assert_eq!(p, vec![vec![0.0, 1.0], vec![1.0, 0.0]]);
+    assert_eq!(l, vec![vec![1.0, 0.0], vec![0.0, 1.0]]);
+    assert_eq!(u, vec![vec![3.0, 1.0], vec![0.0, 2.0]]);
+
+    let matrix_b = Matrix::from_vec(vec![vec![4.0], vec![5.0]]).unwrap();
+    let matrix_x = matrix_a.solve(&matrix_b).unwrap();
+    assert_eq!(matrix_x, vec![vec![1.0], vec![2.0]]);
+}
+
+#[test]
+fn det_inv() {
+    let matrix_a = Matrix::from_vec(vec![vec![2.0, 0.0], vec![0.0, 4.0]]).unwrap();
+
+    assert_eq!(matrix_a.det().unwrap(), 8.0);
+    assert_eq!(matrix_a.slogdet().unwrap(), (1.0, 8.0f64.ln()));
+    assert_eq!(
+        matrix_a.inv().unwrap(),
+        vec![vec![0.5, 0.0], vec![0.0, 0.25]]
+    );
+
+    let matrix_s = Matrix::from_vec(vec![vec![1.0, 1.0], vec![1.0, 1.0]]).unwrap();
+    assert_eq!(matrix_s.det().unwrap(), 0.0);
+    assert!(matches!(matrix_s.inv(), Err(Error::SingularMatrix { .. })));
+}
+
+#[test]
+fn qr_cholesky_lstsq() {
+    let matrix_a = Matrix::from_vec(vec![vec![3.0f64, 0.0], vec![4.0, 5.0]]).unwrap();
+
+    let (q, r) = matrix_a.qr(QrMode::Complete).unwrap();
+    assert_eq!(q.shape(), vec![2, 2]);
+    assert_eq!(r.shape(), vec![2, 2]);
+    assert!((r.row(0).unwrap().getval(&[0]).unwrap().abs() - 5.0).abs() < 1e-12);
+
+    let matrix_s = Matrix::from_vec(vec![vec![4.0, 2.0], vec![2.0, 5.0]]).unwrap();
+    assert_eq!(
+        matrix_s.cholesky().unwrap(),
+        vec![vec![2.0, 0.0], vec![1.0, 2.0]]
+    );
+    assert!(matches!(
+        matrix_a.cholesky(),
+        Err(Error::NotPositiveDefinite { .. })
+    ));
+
+    let matrix_b = Matrix::from_vec(vec![vec![3.0], vec![9.0]]).unwrap();
+    let matrix_x = matrix_a.lstsq(&matrix_b).unwrap();
+    for val in matrix_x.col(0).unwrap().iter() {
+        assert!((val - 1.0).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn eigen_svd() {
+    let matrix_a = Matrix::from_vec(vec![vec![2.0f64, 1.0], vec![1.0, 2.0]]).unwrap();
+
+    let (w, _) = matrix_a.eigh().unwrap();
+    assert!((w.get(0).unwrap() - 1.0).abs() < 1e-12);
+    assert!((w.get(1).unwrap() - 3.0).abs() < 1e-12);
+
+    let (w, v) = matrix_a.eig().unwrap();
+    assert_eq!(v.shape(), vec![2, 2]);
+    assert!(w.get(0).unwrap().im.abs() < 1e-12);
+
+    let matrix_b = Matrix::from_vec(vec![vec![3.0f64, 0.0], vec![4.0, 5.0]]).unwrap();
+    let (u, s, vt) = matrix_b.svd(true).unwrap();
+    assert_eq!((u.shape(), vt.shape()), (vec![2, 2], vec![2, 2]));
+    assert!((s.get(0).unwrap() - 45f64.sqrt()).abs() < 1e-12);
+
+    assert_eq!(matrix_b.matrix_rank().unwrap(), 2);
+    assert!((matrix_b.cond().unwrap() - 3.0).abs() < 1e-12);
+    assert!((matrix_b.norm_nuc().unwrap() - 4.0 * 5f64.sqrt()).abs() < 1e-12);
+    assert!((matrix_b.norm_spectral().unwrap() - 3.0 * 5f64.sqrt()).abs() < 1e-12);
+    assert_eq!(matrix_b.pinv().unwrap().shape(), vec![2, 2]);
+}
+
+#[test]
+fn structured_solvers_matfun() {
+    let matrix_l = Matrix::from_vec(vec![vec![2.0, 0.0], vec![1.0, 1.0]]).unwrap();
+    let matrix_b = Matrix::from_vec(vec![vec![4.0], vec![5.0]]).unwrap();
+    assert_eq!(
+        matrix_l
+            .solve_triangular(&matrix_b, Triangle::Lower, false, false)
+            .unwrap(),
+        vec![vec![2.0], vec![3.0]]
+    );
+
+    let matrix_ab = Matrix::from_vec(vec![vec![0.0, 1.0], vec![2.0, 2.0], vec![1.0, 0.0]]).unwrap();
+    assert_eq!(
+        matrix_ab.solve_banded(1, 1, &matrix_b).unwrap(),
+        Matrix::solve_tridiagonal(&[1.0], &[2.0, 2.0], &[1.0], &matrix_b).unwrap()
+    );
+
+    let matrix_d = Matrix::from_vec(vec![vec![4.0, 0.0], vec![0.0, 1.0]]).unwrap();
+    assert_eq!(
+        matrix_d.sqrtm().unwrap(),
+        vec![vec![2.0, 0.0], vec![0.0, 1.0]]
+    );
+    assert_eq!(
+        matrix_d.matrix_power(3).unwrap(),
+        vec![vec![64.0, 0.0], vec![0.0, 1.0]]
+    );
+    assert_eq!(matrix_d.shape(), matrix_d.expm().unwrap().shape());
+    assert_eq!(matrix_d.logm().unwrap().shape(), vec![2, 2]);
+}
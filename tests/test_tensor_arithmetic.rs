@@ -1,4 +1,5 @@
-use rs_math::tensor::Tensor;
+use rs_math::s;
+use rs_math::tensor::{self, Error, Tensor};
 
 #[path = "utils/ndim_vec.rs"]
 mod ndim_vec;
@@ -106,15 +107,80 @@ fn add() {
 
     // TensorBase + TensorBase
     assert_eq!(
-        (&tensor_4x3x2 + &tensor_4x1x1),
+        (&tensor_4x3x2 + &tensor_4x1x1).unwrap(),
         tensor_4x3x2.add(&tensor_4x1x1.view()).unwrap()
     );
 
     // TensorView + TensorView
     assert_eq!(
-        (&tensor_4x3x2.view() + &tensor_4x1x1.view()),
+        (&tensor_4x3x2.view() + &tensor_4x1x1.view()).unwrap(),
         tensor_4x3x2.add(&tensor_4x1x1.view()).unwrap()
     );
+
+    // Tensor + TensorView
+    assert_eq!(
+        (tensor_4x3x2.clone() + &tensor_4x1x1.view()).unwrap(),
+        tensor_4x3x2.add(&tensor_4x1x1.view()).unwrap()
+    );
+
+    // TensorView + Tensor
+    assert_eq!(
+        (&tensor_4x3x2.view() + tensor_4x1x1.clone()).unwrap(),
+        tensor_4x3x2.add(&tensor_4x1x1.view()).unwrap()
+    );
+
+    // Tensor + Tensor
+    assert_eq!(
+        (tensor_4x3x2.clone() + tensor_4x1x1.clone()).unwrap(),
+        tensor_4x3x2.add(&tensor_4x1x1.view()).unwrap()
+    );
+
+    // Chained expression
+    let tensor = ((&tensor_4x3x2 + &tensor_4x1x1).unwrap() + &tensor_1x1x1).unwrap();
+    assert_eq!(
+        tensor,
+        tensor_4x3x2
+            .add(&tensor_4x1x1.view())
+            .unwrap()
+            .add(&tensor_1x1x1.view())
+            .unwrap()
+    );
+
+    // Negative: Errors are propagated instead of returning an empty tensor
+    let tensor_4x3x1 = Tensor::from_vec(ndim_vec::ndim_vec_3d::<i32>(&[4, 3, 1], false)).unwrap();
+    let tensor_4x2x1 = Tensor::from_vec(ndim_vec::ndim_vec_3d::<i32>(&[4, 2, 1], false)).unwrap();
+    assert!(matches!(
+        &tensor_4x3x1 + &tensor_4x2x1,
+        Err(Error::ShapeMismatchBroadcast { .. })
+    ));
+    assert!(matches!(
+        tensor_4x3x1.clone() + tensor_4x2x1.clone(),
+        Err(Error::ShapeMismatchBroadcast { .. })
+    ));
+
+    /* Views: Offsets and strides are respected, lower ranks are right-aligned */
+    let tensor_2x3 = Tensor::from_vec(ndim_vec::ndim_vec_2d::<i32>(&[2, 3], false)).unwrap();
+    let tensor_3 = Tensor::from_vec(vec![10, 20, 30]).unwrap();
+    assert_eq!(
+        tensor_2x3
+            .slice(&[1])
+            .unwrap()
+            .add(&tensor_3.view())
+            .unwrap(),
+        vec![14, 25, 36]
+    );
+    assert_eq!(
+        tensor_3.add(&tensor_2x3.view()).unwrap(),
+        vec![vec![11, 22, 33], vec![14, 25, 36]]
+    );
+    assert_eq!(
+        tensor_2x3
+            .transpose()
+            .unwrap()
+            .add(&tensor_2x3.transpose().unwrap())
+            .unwrap(),
+        vec![vec![2, 8], vec![4, 10], vec![6, 12]]
+    );
 }
 
 #[test]
@@ -202,30 +268,43 @@ fn sub() {
 
     // TensorBase - TensorBase
     assert_eq!(
-        (&tensor_4x3x2 - &tensor_4x1x1),
+        (&tensor_4x3x2 - &tensor_4x1x1).unwrap(),
         tensor_4x3x2.sub(&tensor_4x1x1.view()).unwrap()
     );
 
     // TensorView - TensorView
     assert_eq!(
-        (&tensor_4x3x2.view() - &tensor_4x1x1.view()),
+        (&tensor_4x3x2.view() - &tensor_4x1x1.view()).unwrap(),
+        tensor_4x3x2.sub(&tensor_4x1x1.view()).unwrap()
+    );
+
+    // Tensor - Tensor
+    assert_eq!(
+        (tensor_4x3x2.clone() - tensor_4x1x1.clone()).unwrap(),
         tensor_4x3x2.sub(&tensor_4x1x1.view()).unwrap()
     );
+
+    // Negative: Errors are propagated instead of returning an empty tensor
+    let tensor_4x2x1 = Tensor::from_vec(ndim_vec::ndim_vec_3d::<i32>(&[4, 2, 1], false)).unwrap();
+    assert!(matches!(
+        &tensor_4x3x2 - &tensor_4x2x1,
+        Err(Error::ShapeMismatchBroadcast { .. })
+    ));
 }
 
 #[test]
-fn mul() {
+fn matmul_batched() {
     /* 2D tensors: 2x3 * 3x2 = 2x2 */
 
     let tensor_2x3 = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
     let tensor_3x2 = Tensor::from_vec(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
 
-    let tensor_2x2 = tensor_2x3.mul(&tensor_3x2.view()).unwrap();
+    let tensor_2x2 = tensor_2x3.matmul(&tensor_3x2.view()).unwrap();
     assert_eq!(tensor_2x2, vec![vec![22, 28], vec![49, 64]]);
 
     /* 2D tensors: 3x2 * 2x3 = 3x3 */
 
-    let tensor_3x3 = tensor_3x2.mul(&tensor_2x3.view()).unwrap();
+    let tensor_3x3 = tensor_3x2.matmul(&tensor_2x3.view()).unwrap();
     assert_eq!(
         tensor_3x3,
         vec![vec![9, 12, 15], vec![19, 26, 33], vec![29, 40, 51]]
@@ -263,7 +342,7 @@ fn mul() {
     ])
     .unwrap();
 
-    let tensor_2x4x4 = tensor_2x4x3.mul(&tensor_2x3x4.view()).unwrap();
+    let tensor_2x4x4 = tensor_2x4x3.matmul(&tensor_2x3x4.view()).unwrap();
 
     assert_eq!(
         tensor_2x4x4,
@@ -287,7 +366,7 @@ fn mul() {
 
     /* 3D tensors: 2x3x4 * 2x4x3 = 2x3x3 */
 
-    let tensor_2x3x3 = tensor_2x3x4.mul(&tensor_2x4x3.view()).unwrap();
+    let tensor_2x3x3 = tensor_2x3x4.matmul(&tensor_2x4x3.view()).unwrap();
 
     assert_eq!(
         tensor_2x3x3,
@@ -361,7 +440,7 @@ fn mul() {
     ])
     .unwrap();
 
-    let tensor_2x2x2x2 = tensor_2x2x2x3.mul(&tensor_2x2x3x2.view()).unwrap();
+    let tensor_2x2x2x2 = tensor_2x2x2x3.matmul(&tensor_2x2x3x2.view()).unwrap();
 
     #[rustfmt::skip]
     assert_eq!(
@@ -444,7 +523,7 @@ fn mul() {
     ])
     .unwrap();
 
-    let tensor_2x3x2x2 = tensor_2x3x2x3.mul(&tensor_2x1x3x2.view()).unwrap();
+    let tensor_2x3x2x2 = tensor_2x3x2x3.matmul(&tensor_2x1x3x2.view()).unwrap();
 
     #[rustfmt::skip]
     assert_eq!(
@@ -481,23 +560,306 @@ fn mul() {
         ]
     );
 
-    /* Operator overloading (-) */
+    /* 3D tensors: 2x3x2 * 2x2 = 2x3x2 (Broadcast over the missing batch dimension) */
 
-    // TensorBase - TensorBase
+    let tensor_2x3x2 = Tensor::from_vec(ndim_vec::ndim_vec_3d::<i32>(&[2, 3, 2], false)).unwrap();
+    let tensor_2x2 = Tensor::from_vec(vec![vec![1, 0], vec![0, 2]]).unwrap();
+
+    assert_eq!(
+        tensor_2x3x2.matmul(&tensor_2x2.view()).unwrap(),
+        vec![
+            vec![vec![1, 4], vec![3, 8], vec![5, 12]],
+            vec![vec![7, 16], vec![9, 20], vec![11, 24]]
+        ]
+    );
+
+    /* 4D tensors: 1x1x2x3 * 3x3x2 = 1x3x2x2 (Batch dimensions aligned to the right) */
+
+    let tensor_1x1x2x3 = Tensor::from_vec(vec![vec![vec![vec![1, 2, 3], vec![4, 5, 6]]]]).unwrap();
+    let tensor_3x3x2 = Tensor::from_vec(ndim_vec::ndim_vec_3d::<i32>(&[3, 3, 2], false)).unwrap();
+    let tensor_1x3x2x2 = tensor_1x1x2x3.matmul(&tensor_3x3x2.view()).unwrap();
+
+    assert_eq!(tensor_1x3x2x2.shape(), vec![1, 3, 2, 2]);
     assert_eq!(
-        (&tensor_2x1x3x2 * &tensor_2x3x2x2),
-        tensor_2x1x3x2.mul(&tensor_2x3x2x2.view()).unwrap()
+        tensor_1x3x2x2,
+        vec![vec![
+            vec![vec![22, 28], vec![49, 64]],
+            vec![vec![58, 64], vec![139, 154]],
+            vec![vec![94, 100], vec![229, 244]]
+        ]]
     );
+}
 
-    // TensorView - TensorView
+#[test]
+fn matmul() {
+    let tensor_2x3 = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    let tensor_3x2 = Tensor::from_vec(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+    let tensor_3 = Tensor::from_vec(vec![1, 2, 3]).unwrap();
+    let tensor_2 = Tensor::from_vec(vec![1, 2]).unwrap();
+
+    /* 2D x 2D: Matrix product (2x3 * 3x2 = 2x2) */
+
+    assert_eq!(
+        tensor_2x3.matmul(&tensor_3x2.view()).unwrap(),
+        vec![vec![22, 28], vec![49, 64]]
+    );
+
+    /* 2D x 1D: Matrix-vector product (2x3 * 3 = 2) */
+
+    let tensor = tensor_2x3.matmul(&tensor_3.view()).unwrap();
+    assert_eq!(tensor.shape(), vec![2]);
+    assert_eq!(tensor, vec![14, 32]);
+
+    /* 1D x 2D: Vector-matrix product (2 * 2x3 = 3) */
+
+    let tensor = tensor_2.matmul(&tensor_2x3.view()).unwrap();
+    assert_eq!(tensor.shape(), vec![3]);
+    assert_eq!(tensor, vec![9, 12, 15]);
+
+    /* 1D x 1D: Dot product (3 * 3 = scalar) */
+
+    let tensor = tensor_3.matmul(&tensor_3.view()).unwrap();
+    assert_eq!(tensor.shape(), Vec::<usize>::new());
+    assert_eq!(tensor.getval(&[]).unwrap(), 14);
+
+    /* 3D x 1D: Batched matrix-vector product (2x2x3 * 3 = 2x2) */
+
+    let tensor_2x2x3 = Tensor::from_vec(ndim_vec::ndim_vec_3d::<i32>(&[2, 2, 3], false)).unwrap();
+    let tensor = tensor_2x2x3.matmul(&tensor_3.view()).unwrap();
+    assert_eq!(tensor.shape(), vec![2, 2]);
+    assert_eq!(tensor, vec![vec![14, 32], vec![50, 68]]);
+
+    /* 1D x 3D: Batched vector-matrix product (2 * 2x2x3 = 2x3) */
+
+    let tensor = tensor_2.matmul(&tensor_2x2x3.view()).unwrap();
+    assert_eq!(tensor.shape(), vec![2, 3]);
+    assert_eq!(tensor, vec![vec![9, 12, 15], vec![27, 30, 33]]);
+
+    /* Non-contiguous operands: (3x2)^T x 3 = 2 */
+
+    let tensor = tensor_3x2.t().unwrap().matmul(&tensor_3.view()).unwrap();
+    assert_eq!(tensor, vec![22, 28]);
+
+    /* Negative: Inner dimensions mismatch */
+
+    assert!(matches!(
+        tensor_2x3.matmul(&tensor_2.view()),
+        Err(Error::ShapeMismatchBroadcast { .. })
+    ));
+    assert!(matches!(
+        tensor_3.matmul(&tensor_2.view()),
+        Err(Error::ShapeMismatchBroadcast { .. })
+    ));
+
+    /* Negative: 0-d operand */
+
+    let tensor_0d = Tensor::from_vec(5).unwrap();
+    assert!(matches!(
+        tensor_3.matmul(&tensor_0d.view()),
+        Err(Error::InvalidParam { .. })
+    ));
+}
+
+#[test]
+fn bmm() {
+    let tensor_2x2x3 = Tensor::from_vec(ndim_vec::ndim_vec_3d::<i32>(&[2, 2, 3], false)).unwrap();
+    let tensor_2x3x2 = Tensor::from_vec(ndim_vec::ndim_vec_3d::<i32>(&[2, 3, 2], false)).unwrap();
+
+    let tensor = tensor_2x2x3.bmm(&tensor_2x3x2.view()).unwrap();
+    assert_eq!(
+        tensor,
+        vec![
+            vec![vec![22, 28], vec![49, 64]],
+            vec![vec![220, 244], vec![301, 334]]
+        ]
+    );
+
+    /* Negative: No broadcasting over the batch dimension */
+
+    let tensor_1x3x2 = Tensor::from_vec(ndim_vec::ndim_vec_3d::<i32>(&[1, 3, 2], false)).unwrap();
+    assert!(matches!(
+        tensor_2x2x3.bmm(&tensor_1x3x2.view()),
+        Err(Error::ShapeMismatch { .. })
+    ));
+
+    /* Negative: 2D operand */
+
+    let tensor_3x2 = Tensor::from_vec(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+    assert!(matches!(
+        tensor_2x2x3.bmm(&tensor_3x2.view()),
+        Err(Error::DimensionMismatch { .. })
+    ));
+}
+
+#[test]
+fn mv() {
+    let tensor_2x3 = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    let tensor_3 = Tensor::from_vec(vec![1, 0, 2]).unwrap();
+    assert_eq!(tensor_2x3.mv(&tensor_3.view()).unwrap(), vec![7, 16]);
+
+    /* Negative: Matrix operand expected as a vector */
+
+    assert!(matches!(
+        tensor_2x3.mv(&tensor_2x3.t().unwrap()),
+        Err(Error::DimensionMismatch { .. })
+    ));
+}
+
+#[test]
+fn mul_elem() {
+    /* 3D tensors: Partial match on second and third dimensions */
+
+    let tensor_4x3x2 = Tensor::from_vec(ndim_vec::ndim_vec_3d::<i32>(&[4, 3, 2], false)).unwrap();
+    let tensor_4x1x1 = Tensor::from_vec(ndim_vec::ndim_vec_3d::<i32>(&[4, 1, 1], false)).unwrap();
+    let tensor = tensor_4x3x2.mul_elem(&tensor_4x1x1.view()).unwrap();
+
+    // Verify shape
+    assert_eq!(tensor.shape(), vec![4, 3, 2]);
+
+    // Verify data
+    assert_eq!(
+        tensor,
+        vec![
+            vec![vec![1, 2], vec![3, 4], vec![5, 6]],
+            vec![vec![14, 16], vec![18, 20], vec![22, 24]],
+            vec![vec![39, 42], vec![45, 48], vec![51, 54]],
+            vec![vec![76, 80], vec![84, 88], vec![92, 96]]
+        ]
+    );
+
+    // Check that the multiplication is commutative
+    assert_eq!(
+        tensor_4x3x2.mul_elem(&tensor_4x1x1.view()).unwrap(),
+        tensor_4x1x1.mul_elem(&tensor_4x3x2.view()).unwrap()
+    );
+
+    /* 2D tensor * 1D tensor: Broadcasting over the trailing dimension */
+
+    let tensor_2x3 = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    let tensor_3 = Tensor::from_vec(vec![10, 20, 30]).unwrap();
+    let tensor = tensor_2x3.mul_elem(&tensor_3.view()).unwrap();
+    assert_eq!(tensor, vec![vec![10, 40, 90], vec![40, 100, 180]]);
+
+    /* Negative: Incompatible shapes */
+
+    let tensor_2x2 = Tensor::from_vec(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    assert!(matches!(
+        tensor_2x3.mul_elem(&tensor_2x2.view()),
+        Err(Error::ShapeMismatchBroadcast { .. })
+    ));
+
+    /* In-place multiplication */
+
+    let mut tensor_2x3_a = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    tensor_2x3_a.mul_elem_inplace(&tensor_3.view()).unwrap();
+    assert_eq!(tensor_2x3_a, vec![vec![10, 40, 90], vec![40, 100, 180]]);
+
+    // Negative: Broadcast shape differs from the destination shape
+    let mut tensor_3 = Tensor::from_vec(vec![10, 20, 30]).unwrap();
+    assert!(matches!(
+        tensor_3.mul_elem_inplace(&tensor_2x3.view()),
+        Err(Error::ShapeMismatchBroadcast { .. })
+    ));
+    assert_eq!(tensor_3, vec![10, 20, 30]);
+
+    /* Operator overloading (*) */
+
+    // TensorBase * TensorBase
+    assert_eq!(
+        (&tensor_4x3x2 * &tensor_4x1x1).unwrap(),
+        tensor_4x3x2.mul_elem(&tensor_4x1x1.view()).unwrap()
+    );
+
+    // TensorView * TensorView
+    assert_eq!(
+        (&tensor_4x3x2.view() * &tensor_4x1x1.view()).unwrap(),
+        tensor_4x3x2.mul_elem(&tensor_4x1x1.view()).unwrap()
+    );
+
+    /* Operator overloading (*=) */
+
+    let mut tensor_2x3_b = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    tensor_2x3_b *= &tensor_3;
+    assert_eq!(tensor_2x3_b, vec![vec![10, 40, 90], vec![40, 100, 180]]);
+}
+
+#[test]
+fn div() {
+    /* 2D tensors: Integer division with broadcasting */
+
+    let tensor_2x3 = Tensor::from_vec(vec![vec![10, 20, 30], vec![40, 50, 60]]).unwrap();
+    let tensor_2x1 = Tensor::from_vec(vec![vec![10], vec![5]]).unwrap();
+    let tensor = tensor_2x3.div(&tensor_2x1.view()).unwrap();
+
+    // Verify shape
+    assert_eq!(tensor.shape(), vec![2, 3]);
+
+    // Verify data
+    assert_eq!(tensor, vec![vec![1, 2, 3], vec![8, 10, 12]]);
+
+    /* 2D tensors: Float division */
+
+    let tensor_2x2 = Tensor::from_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+    let tensor_2 = Tensor::from_vec(vec![2.0, 0.5]).unwrap();
+    let tensor = tensor_2x2.div(&tensor_2.view()).unwrap();
+    assert_eq!(tensor, vec![vec![0.5, 4.0], vec![1.5, 8.0]]);
+
+    // Float division by zero follows IEEE 754
+    let tensor_zero = Tensor::from_vec(vec![0.0f64]).unwrap();
+    let tensor = tensor_2x2.div(&tensor_zero.view()).unwrap();
+    assert!(tensor.iter().all(|val| val == f64::INFINITY));
+
+    /* Negative: Integer division by zero */
+
+    let tensor_2x1 = Tensor::from_vec(vec![vec![10], vec![0]]).unwrap();
+    let result = tensor_2x3.div(&tensor_2x1.view());
+    assert!(matches!(result, Err(Error::DivisionByZero { ref index }) if *index == vec![1, 0]));
+
+    /* Negative: Integer division overflow */
+
+    let tensor_min = Tensor::from_vec(vec![1, i32::MIN]).unwrap();
+    let tensor_neg = Tensor::from_vec(vec![-1]).unwrap();
+    let result = tensor_min.div(&tensor_neg.view());
+    assert!(matches!(result, Err(Error::Overflow { ref index }) if *index == vec![1]));
+    assert!(matches!(
+        &tensor_min / &tensor_neg,
+        Err(Error::Overflow { .. })
+    ));
+
+    /* In-place division */
+
+    let mut tensor_2x3_a = Tensor::from_vec(vec![vec![10, 20, 30], vec![40, 50, 60]]).unwrap();
+    let tensor_3 = Tensor::from_vec(vec![10, 5, 3]).unwrap();
+    tensor_2x3_a.div_inplace(&tensor_3.view()).unwrap();
+    assert_eq!(tensor_2x3_a, vec![vec![1, 4, 10], vec![4, 10, 20]]);
+
+    // Negative: The tensor is left untouched on error
+    let mut tensor_2x3_b = Tensor::from_vec(vec![vec![10, 20, 30], vec![40, 50, 60]]).unwrap();
+    assert!(matches!(
+        tensor_2x3_b.div_inplace(&tensor_2x1.view()),
+        Err(Error::DivisionByZero { .. })
+    ));
+    assert_eq!(tensor_2x3_b, vec![vec![10, 20, 30], vec![40, 50, 60]]);
+
+    /* Operator overloading (/) */
+
+    // TensorBase / TensorBase
     assert_eq!(
-        (&tensor_2x1x3x2.view() * &tensor_2x3x2x2.view()),
-        tensor_2x1x3x2.mul(&tensor_2x3x2x2.view()).unwrap()
+        (&tensor_2x2 / &tensor_2).unwrap(),
+        tensor_2x2.div(&tensor_2.view()).unwrap()
     );
 
-    // TODO: TensoBase - TensorView
-    // TODO: TensoBase - TensorViewMut
-    // TODO: TensorView - TensorViewMut
+    // TensorView / TensorView
+    assert_eq!(
+        (&tensor_2x2.view() / &tensor_2.view()).unwrap(),
+        tensor_2x2.div(&tensor_2.view()).unwrap()
+    );
+
+    /* Operator overloading (/=) */
+
+    let mut tensor_2x3_c = Tensor::from_vec(vec![vec![10, 20, 30], vec![40, 50, 60]]).unwrap();
+    tensor_2x3_c /= &tensor_3;
+    assert_eq!(tensor_2x3_c, vec![vec![1, 4, 10], vec![4, 10, 20]]);
 }
 
 #[test]
@@ -511,6 +873,13 @@ fn add_scalar() {
     let mut tensor_2x3_a = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
     let mut tensor_2x3_b = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
     assert_eq!(tensor_2x3_a.add_scalar(5), (tensor_2x3_b += 5));
+
+    /* Operator overloading (+) */
+    let tensor_2x3 = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    assert_eq!(
+        (tensor_2x3 + 5).unwrap(),
+        vec![vec![6, 7, 8], vec![9, 10, 11]]
+    );
 }
 
 #[test]
@@ -524,6 +893,13 @@ fn sub_scalar() {
     let mut tensor_2x3_a = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
     let mut tensor_2x3_b = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
     assert_eq!(tensor_2x3_a.sub_scalar(5), (tensor_2x3_b -= 5));
+
+    /* Operator overloading (-) */
+    let tensor_2x3 = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    assert_eq!(
+        (tensor_2x3 - 5).unwrap(),
+        vec![vec![-4, -3, -2], vec![-1, 0, 1]]
+    );
 }
 
 #[test]
@@ -537,4 +913,168 @@ fn mul_scalar() {
     let mut tensor_2x3_a = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
     let mut tensor_2x3_b = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
     assert_eq!(tensor_2x3_a.mul_scalar(5), (tensor_2x3_b *= 5));
+
+    /* Operator overloading (*) */
+    let tensor_2x3 = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    assert_eq!(
+        (tensor_2x3 * 5).unwrap(),
+        vec![vec![5, 10, 15], vec![20, 25, 30]]
+    );
+}
+
+#[test]
+fn div_scalar() {
+    let mut tensor_2x3 = Tensor::from_vec(vec![vec![10, 20, 30], vec![40, 50, 60]]).unwrap();
+    tensor_2x3.div_scalar(10).unwrap();
+    assert_eq!(tensor_2x3, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+    /* Division by zero leaves the tensor untouched */
+    assert!(matches!(
+        tensor_2x3.div_scalar(0),
+        Err(Error::DivisionByZero { .. })
+    ));
+    assert_eq!(tensor_2x3, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+    /* Operator overloading (/=) */
+    let mut tensor_f = Tensor::from_vec(vec![1.0, 2.0, 3.0]).unwrap();
+    tensor_f /= 2.0;
+    assert_eq!(tensor_f, vec![0.5, 1.0, 1.5]);
+
+    /* Operator overloading (/) */
+    assert_eq!(
+        (tensor_2x3 / 2).unwrap(),
+        vec![vec![0, 1, 1], vec![2, 2, 3]]
+    );
+}
+
+#[test]
+fn scalar_view() {
+    /* In-place scalar ops only touch the elements of the view */
+    let mut tensor_2x3 = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    let mut view = tensor_2x3.slice_mut(&[1]).unwrap();
+    view.add_scalar(10);
+    view *= 2;
+    view -= 1;
+    view /= 3;
+    assert_eq!(view.data(), vec![9, 9, 10]);
+
+    let mut tensor_2x3 = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    let mut view = tensor_2x3.slice_info_mut(&s![.., 1]).unwrap();
+    view.mul_scalar(0);
+    assert_eq!(view.nelems(), 2);
+    assert_eq!(view.data(), vec![0, 0]);
+
+    /* Allocating ops on borrowed tensors and views */
+    let tensor_2x3 = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    let view_t = tensor_2x3.transpose().unwrap();
+    assert_eq!(
+        (&view_t + 1).unwrap(),
+        vec![vec![2, 5], vec![3, 6], vec![4, 7]]
+    );
+    assert_eq!(
+        (&tensor_2x3 - 1).unwrap(),
+        vec![vec![0, 1, 2], vec![3, 4, 5]]
+    );
+    assert_eq!(
+        (&view_t * 2).unwrap(),
+        vec![vec![2, 8], vec![4, 10], vec![6, 12]]
+    );
+    assert_eq!(
+        (&tensor_2x3 / 2).unwrap(),
+        vec![vec![0, 1, 1], vec![2, 2, 3]]
+    );
+    assert!(matches!(&tensor_2x3 / 0, Err(Error::DivisionByZero { .. })));
+    assert_eq!(tensor_2x3, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+}
+
+#[test]
+fn scalar_reverse() {
+    let tensor_2x2 = Tensor::from_vec(vec![vec![1.0, 2.0], vec![4.0, 8.0]]).unwrap();
+
+    assert_eq!(
+        (1.0f64 - &tensor_2x2).unwrap(),
+        vec![vec![0.0, -1.0], vec![-3.0, -7.0]]
+    );
+    assert_eq!(
+        (8.0f64 / &tensor_2x2.view()).unwrap(),
+        vec![vec![8.0, 4.0], vec![2.0, 1.0]]
+    );
+    assert_eq!(
+        (2.0f64 * &tensor_2x2.transpose().unwrap()).unwrap(),
+        vec![vec![2.0, 8.0], vec![4.0, 16.0]]
+    );
+    assert_eq!(
+        (1.0f64 + tensor_2x2).unwrap(),
+        vec![vec![2.0, 3.0], vec![5.0, 9.0]]
+    );
+
+    let tensor = Tensor::from_vec(vec![0u8, 5]).unwrap();
+    assert!(matches!(
+        10u8 / &tensor,
+        Err(Error::DivisionByZero { ref index }) if *index == vec![0]
+    ));
+    assert_eq!((10u8 - tensor).unwrap(), vec![10, 5]);
+}
+
+#[test]
+fn broadcast_to() {
+    let tensor_3 = Tensor::from_vec(vec![1, 2, 3]).unwrap();
+
+    let view = tensor_3.broadcast_to(&[2, 3]).unwrap();
+    assert_eq!(view.shape(), vec![2, 3]);
+    assert_eq!(view.strides(), vec![0, 1]);
+    assert_eq!(view, vec![vec![1, 2, 3], vec![1, 2, 3]]);
+    assert!(!view.is_contiguous());
+
+    let tensor_2x1 = Tensor::from_vec(vec![vec![1], vec![2]]).unwrap();
+    let view = tensor_2x1.broadcast_to(&[2, 2, 3]).unwrap();
+    assert_eq!(view.strides(), vec![0, 1, 0]);
+    assert_eq!(
+        view,
+        vec![
+            vec![vec![1, 1, 1], vec![2, 2, 2]],
+            vec![vec![1, 1, 1], vec![2, 2, 2]]
+        ]
+    );
+
+    /* Views keep their offset */
+    let tensor_2x3 = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    let row_1 = tensor_2x3.slice(&[1]).unwrap();
+    let view = row_1.broadcast_to(&[2, 3]).unwrap();
+    assert_eq!(view, vec![vec![4, 5, 6], vec![4, 5, 6]]);
+
+    /* Broadcast operands work with element-wise operations */
+    assert_eq!(
+        tensor_2x3.add(&view).unwrap(),
+        vec![vec![5, 7, 9], vec![8, 10, 12]]
+    );
+    assert_eq!(view.sum_axis(&[0], false).unwrap(), vec![8, 10, 12]);
+
+    assert!(matches!(
+        tensor_2x3.broadcast_to(&[3, 3]),
+        Err(Error::ShapeMismatchBroadcast { .. })
+    ));
+    assert!(matches!(
+        tensor_2x3.broadcast_to(&[3]),
+        Err(Error::ShapeMismatchBroadcast { .. })
+    ));
+}
+
+#[test]
+fn broadcast_shapes() {
+    assert_eq!(
+        tensor::broadcast_shapes(&[&[2, 1], &[3]]).unwrap(),
+        vec![2, 3]
+    );
+    assert_eq!(
+        tensor::broadcast_shapes(&[&[4, 1, 1], &[3, 1], &[1, 5]]).unwrap(),
+        vec![4, 3, 5]
+    );
+    assert_eq!(tensor::broadcast_shapes(&[&[2, 3]]).unwrap(), vec![2, 3]);
+    assert_eq!(tensor::broadcast_shapes(&[]).unwrap(), Vec::<usize>::new());
+
+    assert!(matches!(
+        tensor::broadcast_shapes(&[&[2, 1], &[3], &[2, 4]]),
+        Err(Error::ShapeMismatchBroadcast { .. })
+    ));
 }
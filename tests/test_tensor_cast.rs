@@ -0,0 +1,144 @@
+use rs_math::tensor::{CastPolicy, Complex, Error, Tensor};
+
+#[test]
+fn cast() {
+    let tensor_u8 = Tensor::from_vec(vec![vec![0u8, 128, 255], vec![1, 2, 3]]).unwrap();
+
+    let tensor_f32 = tensor_u8.cast::<f32>().unwrap();
+    assert_eq!(
+        tensor_f32,
+        vec![vec![0.0, 128.0, 255.0], vec![1.0, 2.0, 3.0]]
+    );
+
+    let tensor_i64 = tensor_f32.cast::<i64>().unwrap();
+    assert_eq!(tensor_i64, vec![vec![0, 128, 255], vec![1, 2, 3]]);
+
+    /* Floats are truncated towards zero */
+    let tensor = Tensor::from_vec(vec![1.9f64, -1.9, 0.5]).unwrap();
+    assert_eq!(tensor.cast::<i32>().unwrap(), vec![1, -1, 0]);
+
+    /* Views are cast in logical order */
+    let tensor_t = tensor_u8.transpose().unwrap();
+    let tensor_c = tensor_t.cast::<u32>().unwrap();
+    assert!(tensor_c.is_contiguous());
+    assert_eq!(tensor_c, vec![vec![0, 1], vec![128, 2], vec![255, 3]]);
+}
+
+#[test]
+fn cast_checked() {
+    let tensor = Tensor::from_vec(vec![vec![1i32, 2], vec![-3, 4]]).unwrap();
+    assert!(matches!(
+        tensor.cast::<u8>(),
+        Err(Error::InvalidCast { ref index, .. }) if *index == vec![1, 0]
+    ));
+
+    let tensor = Tensor::from_vec(vec![0.0f32, f32::NAN]).unwrap();
+    assert!(matches!(
+        tensor.cast::<i8>(),
+        Err(Error::InvalidCast { ref index, .. }) if *index == vec![1]
+    ));
+
+    /* Finite values that overflow a narrower float type */
+    let tensor = Tensor::from_vec(vec![1e300f64]).unwrap();
+    assert!(matches!(
+        tensor.cast::<f32>(),
+        Err(Error::InvalidCast { .. })
+    ));
+
+    /* Infinity and NaN carry over between float types */
+    let tensor = Tensor::from_vec(vec![f64::INFINITY, f64::NAN]).unwrap();
+    let tensor_f32 = tensor.cast::<f32>().unwrap();
+    assert_eq!(tensor_f32.getval(&[0]).unwrap(), f32::INFINITY);
+    assert!(tensor_f32.getval(&[1]).unwrap().is_nan());
+}
+
+#[test]
+fn cast_saturating() {
+    let tensor = Tensor::from_vec(vec![-300i32, -5, 100, 300]).unwrap();
+    assert_eq!(
+        tensor.cast_with::<u8>(CastPolicy::Saturating).unwrap(),
+        vec![0, 0, 100, 255]
+    );
+    assert_eq!(
+        tensor.cast_with::<i8>(CastPolicy::Saturating).unwrap(),
+        vec![-128, -5, 100, 127]
+    );
+
+    let tensor = Tensor::from_vec(vec![f64::NAN, -1e10, 1e10, 1e300]).unwrap();
+    assert_eq!(
+        tensor.cast_with::<i32>(CastPolicy::Saturating).unwrap(),
+        vec![0, i32::MIN, i32::MAX, i32::MAX]
+    );
+    assert_eq!(
+        tensor
+            .cast_with::<f32>(CastPolicy::Saturating)
+            .unwrap()
+            .getval(&[3])
+            .unwrap(),
+        f32::MAX
+    );
+}
+
+#[test]
+fn cast_wrapping() {
+    let tensor = Tensor::from_vec(vec![-1i32, 256, 300]).unwrap();
+    assert_eq!(
+        tensor.cast_with::<u8>(CastPolicy::Wrapping).unwrap(),
+        vec![255, 0, 44]
+    );
+
+    let tensor = Tensor::from_vec(vec![u64::MAX]).unwrap();
+    assert_eq!(
+        tensor.cast_with::<i64>(CastPolicy::Wrapping).unwrap(),
+        vec![-1]
+    );
+
+    /* Float to integer conversions saturate, as with `as` */
+    let tensor = Tensor::from_vec(vec![-1.5f32, 300.0]).unwrap();
+    assert_eq!(
+        tensor.cast_with::<u8>(CastPolicy::Wrapping).unwrap(),
+        vec![0, 255]
+    );
+}
+
+#[test]
+fn cast_complex() {
+    let tensor = Tensor::from_vec(vec![vec![1i32, -2], vec![3, 4]]).unwrap();
+    let tensor_c = tensor.t().unwrap().to_complex::<f64>().unwrap();
+    assert_eq!(
+        tensor_c.data(),
+        vec![
+            Complex::new(1.0, 0.0),
+            Complex::new(3.0, 0.0),
+            Complex::new(-2.0, 0.0),
+            Complex::new(4.0, 0.0)
+        ]
+    );
+
+    /* Both parts are converted across precisions */
+    let tensor =
+        Tensor::from_vec(vec![Complex::new(0.5f64, -1.5), Complex::new(2.0, 1e300)]).unwrap();
+    assert!(matches!(
+        tensor.cast_complex::<f32>(),
+        Err(Error::InvalidCast { index, .. }) if index == vec![1]
+    ));
+    assert_eq!(
+        tensor
+            .cast_complex_with::<f32>(CastPolicy::Saturating)
+            .unwrap()
+            .data(),
+        vec![Complex::new(0.5, -1.5), Complex::new(2.0, f32::MAX)]
+    );
+    let tensor_d = tensor_c
+        .cast_complex::<f32>()
+        .unwrap()
+        .cast_complex::<f64>()
+        .unwrap();
+    assert_eq!(tensor_d, tensor_c);
+
+    let tensor = Tensor::from_vec(vec![1e300f64]).unwrap();
+    assert!(matches!(
+        tensor.to_complex::<f32>(),
+        Err(Error::InvalidCast { .. })
+    ));
+}
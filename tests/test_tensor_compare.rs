@@ -0,0 +1,162 @@
+use rs_math::tensor::{Error, Tensor};
+
+#[test]
+fn compare() {
+    let tensor_a = Tensor::from_vec(vec![vec![1, 5, 3], vec![4, 2, 6]]).unwrap();
+    let tensor_b = Tensor::from_vec(vec![vec![1, 2, 6], vec![4, 5, 3]]).unwrap();
+
+    assert_eq!(
+        tensor_a.eq_elem(&tensor_b.view()).unwrap(),
+        vec![vec![true, false, false], vec![true, false, false]]
+    );
+    assert_eq!(
+        tensor_a.ne_elem(&tensor_b.view()).unwrap(),
+        vec![vec![false, true, true], vec![false, true, true]]
+    );
+    /* `eq` and `ne` still compare whole tensors */
+    assert!(tensor_a.eq(&tensor_a));
+    assert!(tensor_a.ne(&tensor_b));
+    assert_eq!(
+        tensor_a.lt(&tensor_b.view()).unwrap(),
+        vec![vec![false, false, true], vec![false, true, false]]
+    );
+    assert_eq!(
+        tensor_a.le(&tensor_b.view()).unwrap(),
+        vec![vec![true, false, true], vec![true, true, false]]
+    );
+    assert_eq!(
+        tensor_a.gt(&tensor_b.view()).unwrap(),
+        vec![vec![false, true, false], vec![false, false, true]]
+    );
+    assert_eq!(
+        tensor_a.ge(&tensor_b.view()).unwrap(),
+        vec![vec![true, true, false], vec![true, false, true]]
+    );
+
+    /* Broadcasting against a row and a scalar */
+    let row = Tensor::from_vec(vec![2, 2, 6]).unwrap();
+    assert_eq!(
+        tensor_a.ge(&row.view()).unwrap(),
+        vec![vec![false, true, false], vec![true, true, true]]
+    );
+
+    let scalar = Tensor::from_vec(vec![3]).unwrap();
+    assert_eq!(
+        tensor_a.transpose().unwrap().gt(&scalar.view()).unwrap(),
+        vec![vec![false, true], vec![true, false], vec![false, true]]
+    );
+
+    /* NaN compares unequal to everything */
+    let tensor_f = Tensor::from_vec(vec![f64::NAN, 1.0]).unwrap();
+    assert_eq!(
+        tensor_f.eq_elem(&tensor_f.view()).unwrap(),
+        vec![false, true]
+    );
+
+    assert!(matches!(
+        tensor_a.lt(&Tensor::from_vec(vec![1, 2]).unwrap().view()),
+        Err(Error::ShapeMismatchBroadcast { .. })
+    ));
+}
+
+#[test]
+fn logical() {
+    let mask_a = Tensor::from_vec(vec![vec![true, true], vec![false, false]]).unwrap();
+    let mask_b = Tensor::from_vec(vec![true, false]).unwrap();
+
+    assert_eq!(
+        mask_a.and(&mask_b.view()).unwrap(),
+        vec![vec![true, false], vec![false, false]]
+    );
+    assert_eq!(
+        mask_a.or(&mask_b.view()).unwrap(),
+        vec![vec![true, true], vec![true, false]]
+    );
+    assert_eq!(
+        mask_a.xor(&mask_b.view()).unwrap(),
+        vec![vec![false, true], vec![true, false]]
+    );
+    assert_eq!(mask_a.not(), vec![vec![false, false], vec![true, true]]);
+}
+
+#[test]
+fn any_all() {
+    let mask = Tensor::from_vec(vec![vec![true, false], vec![true, true]]).unwrap();
+
+    assert!(mask.any());
+    assert!(!mask.all());
+    assert_eq!(mask.all_axis(&[0], false).unwrap(), vec![true, false]);
+    assert_eq!(mask.all_axis(&[1], false).unwrap(), vec![false, true]);
+    assert_eq!(
+        mask.any_axis(&[1], true).unwrap(),
+        vec![vec![true], vec![true]]
+    );
+
+    let empty = Tensor::<bool>::from_zeros(&[0]).unwrap();
+    assert!(!empty.any());
+    assert!(empty.all());
+}
+
+#[test]
+fn where_() {
+    let mask = Tensor::from_vec(vec![vec![true], vec![false]]).unwrap();
+    let tensor_a = Tensor::from_vec(vec![1, 2, 3]).unwrap();
+    let tensor_b = Tensor::from_vec(vec![0]).unwrap();
+
+    let tensor_r = Tensor::where_(&mask.view(), &tensor_a.view(), &tensor_b.view()).unwrap();
+    assert_eq!(tensor_r.shape(), vec![2, 3]);
+    assert_eq!(tensor_r, vec![vec![1, 2, 3], vec![0, 0, 0]]);
+
+    /* Clip negative values using a comparison mask */
+    let tensor = Tensor::from_vec(vec![-1.5, 2.0, -0.5]).unwrap();
+    let zero = Tensor::from_vec(vec![0.0]).unwrap();
+    let mask = tensor.lt(&zero.view()).unwrap();
+    assert_eq!(
+        Tensor::where_(&mask.view(), &zero.view(), &tensor.view()).unwrap(),
+        vec![0.0, 2.0, 0.0]
+    );
+
+    assert!(matches!(
+        Tensor::where_(
+            &Tensor::from_vec(vec![true, false]).unwrap().view(),
+            &tensor_a.view(),
+            &tensor_b.view()
+        ),
+        Err(Error::ShapeMismatchBroadcast { .. })
+    ));
+}
+
+#[test]
+fn masked() {
+    let tensor = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    let mask = Tensor::from_vec(vec![vec![true, false, true], vec![false, false, true]]).unwrap();
+
+    assert_eq!(
+        tensor.masked_fill(&mask.view(), 0).unwrap(),
+        vec![vec![0, 2, 0], vec![4, 5, 0]]
+    );
+    assert_eq!(tensor.masked_select(&mask.view()).unwrap(), vec![1, 3, 6]);
+
+    /* The mask broadcasts along the rows */
+    let mask_col = Tensor::from_vec(vec![vec![false], vec![true]]).unwrap();
+    assert_eq!(
+        tensor.masked_fill(&mask_col.view(), -1).unwrap(),
+        vec![vec![1, 2, 3], vec![-1, -1, -1]]
+    );
+    assert_eq!(
+        tensor.masked_select(&mask_col.view()).unwrap(),
+        vec![4, 5, 6]
+    );
+
+    /* Selecting with a mask built from the tensor itself */
+    let three = Tensor::from_vec(vec![3]).unwrap();
+    let mask = tensor.gt(&three.view()).unwrap();
+    assert_eq!(tensor.masked_select(&mask.view()).unwrap(), vec![4, 5, 6]);
+
+    /* masked_fill keeps the shape of the tensor */
+    let mask_big = Tensor::from_vec(vec![vec![vec![true; 3]; 2]; 2]).unwrap();
+    assert!(matches!(
+        tensor.masked_fill(&mask_big.view(), 0),
+        Err(Error::ShapeMismatchBroadcast { .. })
+    ));
+}
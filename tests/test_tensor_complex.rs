@@ -0,0 +1,101 @@
+use rs_math::matrix::Matrix;
+use rs_math::tensor::{Complex, Tensor};
+
+fn c(re: f64, im: f64) -> Complex<f64> {
+    Complex::new(re, im)
+}
+
+#[test]
+fn complex_arithmetic() {
+    let tensor_a = Tensor::from_vec(vec![c(1.0, 2.0), c(3.0, -1.0)]).unwrap();
+    let tensor_b = Tensor::from_vec(vec![c(0.0, 1.0), c(2.0, 2.0)]).unwrap();
+
+    assert_eq!(
+        tensor_a.add(&tensor_b.view()).unwrap(),
+        vec![c(1.0, 3.0), c(5.0, 1.0)]
+    );
+    assert_eq!(
+        tensor_a.sub(&tensor_b.view()).unwrap(),
+        vec![c(1.0, 1.0), c(1.0, -3.0)]
+    );
+    assert_eq!(
+        tensor_a.mul_elem(&tensor_b.view()).unwrap(),
+        vec![c(-2.0, 1.0), c(8.0, 4.0)]
+    );
+    assert_eq!(
+        tensor_a.div(&tensor_b.view()).unwrap(),
+        vec![c(2.0, -1.0), c(0.5, -1.0)]
+    );
+
+    /* Matrix products use complex multiplication */
+    let tensor_m = Tensor::from_vec(vec![vec![c(0.0, 1.0), c(1.0, 0.0)]]).unwrap();
+    let tensor_v = Tensor::from_vec(vec![vec![c(0.0, 1.0)], vec![c(2.0, 0.0)]]).unwrap();
+    assert_eq!(
+        tensor_m.matmul(&tensor_v.view()).unwrap(),
+        vec![vec![c(1.0, 0.0)]]
+    );
+}
+
+#[test]
+fn complex_conj_abs_arg() {
+    let tensor = Tensor::from_vec(vec![c(3.0, 4.0), c(0.0, -2.0), c(-1.0, 0.0)]).unwrap();
+
+    assert_eq!(
+        tensor.conj(),
+        vec![c(3.0, -4.0), c(0.0, 2.0), c(-1.0, -0.0)]
+    );
+    assert_eq!(tensor.abs(), vec![5.0, 2.0, 1.0]);
+    assert_eq!(
+        tensor.arg(),
+        vec![
+            4.0f64.atan2(3.0),
+            -std::f64::consts::FRAC_PI_2,
+            std::f64::consts::PI
+        ]
+    );
+}
+
+#[test]
+fn complex_real_imag() {
+    let tensor = Tensor::from_vec(vec![
+        vec![Complex::new(1.0f32, -1.0), Complex::new(2.0, -2.0)],
+        vec![Complex::new(3.0, -3.0), Complex::new(4.0, -4.0)],
+    ])
+    .unwrap();
+
+    assert_eq!(tensor.real(), vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    assert_eq!(tensor.imag(), vec![vec![-1.0, -2.0], vec![-3.0, -4.0]]);
+
+    /* Views of views keep their layout */
+    let view_t = tensor.transpose().unwrap();
+    assert_eq!(view_t.real(), vec![vec![1.0, 3.0], vec![2.0, 4.0]]);
+
+    let row = tensor.slice(&[1]).unwrap();
+    assert_eq!(row.imag(), vec![-3.0, -4.0]);
+}
+
+#[test]
+fn complex_conj_t() {
+    let tensor = Tensor::from_vec(vec![
+        vec![c(1.0, 1.0), c(2.0, 0.0), c(0.0, 3.0)],
+        vec![c(4.0, -1.0), c(5.0, 5.0), c(6.0, 0.0)],
+    ])
+    .unwrap();
+
+    let tensor_h = tensor.conj_t();
+    assert_eq!(tensor_h.shape(), vec![3, 2]);
+    assert_eq!(
+        tensor_h,
+        vec![
+            vec![c(1.0, -1.0), c(4.0, 1.0)],
+            vec![c(2.0, 0.0), c(5.0, -5.0)],
+            vec![c(0.0, -3.0), c(6.0, 0.0)],
+        ]
+    );
+
+    let matrix = Matrix::from_vec(vec![vec![c(1.0, 2.0), c(0.0, 1.0)]]).unwrap();
+    assert_eq!(
+        matrix.conj_t(),
+        vec![vec![c(1.0, -2.0)], vec![c(0.0, -1.0)]]
+    );
+}
@@ -31,6 +31,207 @@ fn from_zeros() {
     // TODO: Negative test related to input data sanity
 }
 
+#[test]
+fn from_vec_ragged() {
+    let tensor = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5]]);
+    assert!(matches!(
+        tensor,
+        Err(Error::RaggedData { ref index, len: 2, expected: 3 }) if *index == vec![1]
+    ));
+
+    #[rustfmt::skip]
+    let tensor = Tensor::from_vec_ref(&vec![
+        vec![vec![1, 2], vec![3, 4]],
+        vec![vec![5, 6], vec![7, 8, 9]],
+    ]);
+    assert!(matches!(
+        tensor,
+        Err(Error::RaggedData { ref index, len: 3, expected: 2 }) if *index == vec![1, 1]
+    ));
+
+    let tensor = Tensor::from_vec(vec![vec![1, 2], vec![3, 4], vec![]]);
+    assert!(matches!(tensor, Err(Error::RaggedData { .. })));
+
+    /* An empty first row is only valid if all rows are empty */
+    let tensor = Tensor::<i32>::from_vec(vec![vec![], vec![1]]);
+    assert!(matches!(tensor, Err(Error::RaggedData { .. })));
+
+    let tensor = Tensor::from_vec(vec![Vec::<i32>::new(), vec![]]).unwrap();
+    assert_eq!(tensor.shape(), vec![2, 0]);
+}
+
+#[test]
+fn from_shape() {
+    let tensor = Tensor::from_shape(&[2, 3], vec![1, 2, 3, 4, 5, 6]).unwrap();
+    assert_eq!(tensor, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+    let tensor = Tensor::from_shape(&[], vec![7]).unwrap();
+    assert_eq!(tensor.ndim(), 0);
+    assert_eq!(tensor.data(), vec![7]);
+
+    assert!(matches!(
+        Tensor::from_shape(&[2, 3], vec![1, 2, 3, 4, 5]),
+        Err(Error::DataLengthMismatch { len: 5, .. })
+    ));
+    assert!(matches!(
+        Tensor::from_shape(&[2, 2], vec![1, 2, 3, 4, 5]),
+        Err(Error::DataLengthMismatch { len: 5, .. })
+    ));
+    assert!(matches!(
+        Tensor::<i32>::from_shape(&[], vec![]),
+        Err(Error::DataLengthMismatch { len: 0, .. })
+    ));
+}
+
+#[test]
+fn element_types() {
+    let tensor = Tensor::from_vec(vec![vec![1i16, -2], vec![3, -4]]).unwrap();
+    assert_eq!(tensor.sum(), -2);
+    assert_eq!(tensor.transpose().unwrap(), vec![vec![1, 3], vec![-2, -4]]);
+
+    let tensor = Tensor::<u16>::arange(0, 60000, 20000).unwrap();
+    assert_eq!(tensor, vec![0, 20000, 40000]);
+
+    /* Index tensors */
+    let tensor = Tensor::from_fn(&[2, 2], |index| index[0] * 2 + index[1]).unwrap();
+    assert_eq!(tensor, vec![vec![0usize, 1], vec![2, 3]]);
+    assert_eq!(tensor.max_axis(&[1], false).unwrap(), vec![1, 3]);
+
+    let tensor = Tensor::from_vec(vec![-1isize, 1]).unwrap();
+    assert_eq!(tensor.add(&tensor.view()).unwrap(), vec![-2, 2]);
+
+    let tensor = Tensor::from_vec(vec![i128::MAX / 2, 1]).unwrap();
+    assert_eq!(tensor.sum(), i128::MAX / 2 + 1);
+    assert!(matches!(
+        tensor.cast::<u64>(),
+        Err(Error::InvalidCast { .. })
+    ));
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn half_precision() {
+    use half::{bf16, f16};
+
+    let tensor = Tensor::from_vec(vec![0.5f32, 1.5, -2.0])
+        .unwrap()
+        .cast::<f16>()
+        .unwrap();
+    assert_eq!(tensor.sum(), f16::from_f32(0.0));
+    assert_eq!(
+        tensor
+            .mul_elem(&tensor.view())
+            .unwrap()
+            .cast::<f32>()
+            .unwrap(),
+        vec![0.25, 2.25, 4.0]
+    );
+
+    let tensor = Tensor::<bf16>::linspace(bf16::from_f32(0.0), bf16::from_f32(1.0), 3).unwrap();
+    assert_eq!(tensor.cast::<f32>().unwrap(), vec![0.0, 0.5, 1.0]);
+    assert_eq!(tensor.mean(), bf16::from_f32(0.5));
+}
+
+#[test]
+fn ones_full_eye() {
+    let tensor = Tensor::<i32>::ones(&[2, 3]).unwrap();
+    assert_eq!(tensor, vec![vec![1, 1, 1], vec![1, 1, 1]]);
+
+    let tensor = Tensor::full(&[3, 1], 2.5).unwrap();
+    assert_eq!(tensor.strides(), [1, 1]);
+    assert_eq!(tensor, vec![vec![2.5], vec![2.5], vec![2.5]]);
+
+    let tensor = Tensor::<u8>::eye(3, 3).unwrap();
+    assert_eq!(tensor, vec![vec![1, 0, 0], vec![0, 1, 0], vec![0, 0, 1]]);
+
+    let tensor = Tensor::<i64>::eye(2, 4).unwrap();
+    assert_eq!(tensor, vec![vec![1, 0, 0, 0], vec![0, 1, 0, 0]]);
+
+    let tensor = Tensor::<i64>::eye(3, 2).unwrap();
+    assert_eq!(tensor, vec![vec![1, 0], vec![0, 1], vec![0, 0]]);
+}
+
+#[test]
+fn from_fn() {
+    let tensor = Tensor::from_fn(&[2, 3, 2], |index| {
+        (index[0] * 100 + index[1] * 10 + index[2]) as i32
+    })
+    .unwrap();
+    assert_eq!(
+        tensor,
+        vec![
+            vec![vec![0, 1], vec![10, 11], vec![20, 21]],
+            vec![vec![100, 101], vec![110, 111], vec![120, 121]]
+        ]
+    );
+
+    let mut calls = 0;
+    let tensor = Tensor::from_fn(&[4], |_| {
+        calls += 1;
+        calls
+    })
+    .unwrap();
+    assert_eq!(tensor, vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn arange() {
+    assert_eq!(Tensor::arange(0, 5, 1).unwrap(), vec![0, 1, 2, 3, 4]);
+    assert_eq!(Tensor::arange(1, 10, 3).unwrap(), vec![1, 4, 7]);
+    assert_eq!(Tensor::arange(5, 0, -2).unwrap(), vec![5, 3, 1]);
+    assert_eq!(
+        Tensor::<u8>::arange(250, 255, 2).unwrap(),
+        vec![250, 252, 254]
+    );
+    assert_eq!(
+        Tensor::<i8>::arange(-100, 100, 50).unwrap(),
+        vec![-100, -50, 0, 50]
+    );
+    assert_eq!(Tensor::<u32>::arange(5, 0, 1).unwrap().shape(), vec![0]);
+
+    let tensor = Tensor::arange(0.0, 1.0, 0.25).unwrap();
+    assert_eq!(tensor, vec![0.0, 0.25, 0.5, 0.75]);
+
+    // The length is that of NumPy, despite rounding in the step
+    let tensor = Tensor::<f64>::arange(0.0, 1.0, 0.1).unwrap();
+    assert_eq!(tensor.shape(), vec![10]);
+
+    // Values are start + i * step, without accumulated rounding errors
+    assert_eq!(tensor.getval(&[8]).unwrap(), 0.8);
+    assert_eq!(tensor.getval(&[9]).unwrap(), 0.9);
+    let tensor = Tensor::<f32>::arange(1.0, 0.0, -0.1).unwrap();
+    assert_eq!(tensor.getval(&[7]).unwrap(), 1.0 - 7.0 * 0.1);
+
+    assert!(matches!(
+        Tensor::arange(0, 5, 0),
+        Err(Error::InvalidParam { .. })
+    ));
+    assert!(matches!(
+        Tensor::arange(0.0, f64::INFINITY, 1.0),
+        Err(Error::InvalidParam { .. })
+    ));
+}
+
+#[test]
+fn linspace() {
+    let tensor = Tensor::<f64>::linspace(0.0, 1.0, 5).unwrap();
+    assert_eq!(tensor, vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+
+    let tensor = Tensor::<f32>::linspace(2.0, -2.0, 3).unwrap();
+    assert_eq!(tensor, vec![2.0, 0.0, -2.0]);
+
+    let tensor = Tensor::<f64>::linspace(0.0, 0.3, 4).unwrap();
+    assert_eq!(tensor.getval(&[3]).unwrap(), 0.3);
+
+    assert_eq!(Tensor::<f64>::linspace(3.0, 4.0, 1).unwrap(), vec![3.0]);
+
+    let tensor = Tensor::<f64>::logspace(0.0, 3.0, 4, 10.0).unwrap();
+    assert_eq!(tensor, vec![1.0, 10.0, 100.0, 1000.0]);
+
+    let tensor = Tensor::<f32>::logspace(1.0, 4.0, 4, 2.0).unwrap();
+    assert_eq!(tensor, vec![2.0, 4.0, 8.0, 16.0]);
+}
+
 #[test]
 fn data() {
     // 1D tensor
@@ -456,7 +657,7 @@ fn update() {
         .update(&[], vec![vec![51, 52], vec![53, 54]])
         .unwrap();
 
-    assert_eq!(tensor_3d_sl.data(), vec![51, 52, 53, 54, 45, 46, 47, 48]);
+    assert_eq!(tensor_3d_sl.data(), vec![51, 52, 53, 54]);
 
     // TODO: 1D tensor
     // TODO: 4D tensor
@@ -533,12 +734,213 @@ fn flatten() {
 
     #[rustfmt::skip]
     assert_eq!(
-        tensor_4d.flatten().unwrap(),
+        tensor_4d.flatten(1, 3).unwrap(),
         vec![
             vec![1, 2, 3, 4, 5, 6],
             vec![7, 8, 9, 10, 11, 12]
         ]
     );
+
+    let flat = tensor_4d.flatten(0, 3).unwrap();
+    assert_eq!(flat.shape(), vec![12]);
+    assert_eq!(flat, (1..=12).collect::<Vec<_>>());
+    assert_eq!(tensor_4d.flatten(1, 2).unwrap().shape(), vec![2, 3, 2]);
+
+    /* Non-contiguous input is copied */
+    let tensor_t = tensor_4d.transpose().unwrap();
+    assert_eq!(
+        tensor_t.flatten(0, 2).unwrap(),
+        vec![
+            vec![1, 7],
+            vec![3, 9],
+            vec![5, 11],
+            vec![2, 8],
+            vec![4, 10],
+            vec![6, 12]
+        ]
+    );
+
+    assert!(matches!(
+        tensor_4d.flatten(2, 1),
+        Err(Error::InvalidParam { .. })
+    ));
+    assert!(matches!(
+        tensor_4d.flatten(0, 4),
+        Err(Error::InvalidAxis { .. })
+    ));
+
+    let mut tensor_2x3 = Tensor::from_vec(ndim_vec::ndim_vec_2d::<i32>(&[2, 3], false)).unwrap();
+    let mut flat = tensor_2x3.flatten_mut(0, 1).unwrap();
+    flat.setval(&[4], 0).unwrap();
+    assert_eq!(flat.data(), vec![1, 2, 3, 4, 0, 6]);
+}
+
+#[test]
+fn reshape() {
+    let tensor_2x3x4 = Tensor::from_vec(ndim_vec::ndim_vec_3d::<i32>(&[2, 3, 4], false)).unwrap();
+
+    let reshaped = tensor_2x3x4.reshape(&[4, -1]).unwrap();
+    assert_eq!(reshaped.shape(), vec![4, 6]);
+    assert_eq!(reshaped.strides(), vec![6, 1]);
+    assert_eq!(
+        reshaped,
+        vec![
+            vec![1, 2, 3, 4, 5, 6],
+            vec![7, 8, 9, 10, 11, 12],
+            vec![13, 14, 15, 16, 17, 18],
+            vec![19, 20, 21, 22, 23, 24]
+        ]
+    );
+
+    /* Views of a sub-tensor keep their offset */
+    let slice_1 = tensor_2x3x4.slice(&[1]).unwrap();
+    let reshaped = slice_1.reshape(&[2, 1, 6]).unwrap();
+    assert_eq!(
+        reshaped,
+        vec![
+            vec![vec![13, 14, 15, 16, 17, 18]],
+            vec![vec![19, 20, 21, 22, 23, 24]]
+        ]
+    );
+
+    /* A transposed view can't be reshaped without copying */
+    let tensor_2x3 = Tensor::from_vec(ndim_vec::ndim_vec_2d::<i32>(&[2, 3], false)).unwrap();
+    let tensor_t = tensor_2x3.transpose().unwrap();
+    let reshaped = tensor_t.reshape(&[-1]).unwrap();
+    assert_eq!(reshaped, vec![1, 4, 2, 5, 3, 6]);
+
+    /* Splitting a transposed axis keeps the view */
+    let reshaped = tensor_t.reshape(&[3, 2, 1]).unwrap();
+    assert_eq!(reshaped.strides(), vec![1, 3, 3]);
+    assert_eq!(
+        reshaped,
+        vec![
+            vec![vec![1], vec![4]],
+            vec![vec![2], vec![5]],
+            vec![vec![3], vec![6]]
+        ]
+    );
+
+    assert!(matches!(
+        tensor_2x3.reshape(&[4, -1]),
+        Err(Error::InvalidParam { .. })
+    ));
+    assert!(matches!(
+        tensor_2x3.reshape(&[-1, -1]),
+        Err(Error::InvalidParam { .. })
+    ));
+    assert!(matches!(
+        tensor_2x3.reshape(&[5]),
+        Err(Error::ShapeMismatch { .. })
+    ));
+
+    let mut tensor_2x3 = Tensor::from_vec(ndim_vec::ndim_vec_2d::<i32>(&[2, 3], false)).unwrap();
+    let mut reshaped = tensor_2x3.reshape_mut(&[3, 2]).unwrap();
+    reshaped.setval(&[1, 1], 0).unwrap();
+    assert_eq!(reshaped.data(), vec![1, 2, 3, 0, 5, 6]);
+
+    let mut tensor_3x2 = Tensor::from_vec(ndim_vec::ndim_vec_2d::<i32>(&[3, 2], false)).unwrap();
+    let mut tensor_t = tensor_3x2.transpose_mut().unwrap();
+    assert!(matches!(
+        tensor_t.reshape_mut(&[6]),
+        Err(Error::InvalidParam { .. })
+    ));
+}
+
+#[test]
+fn squeeze() {
+    let tensor_2x1x3 = Tensor::from_vec(ndim_vec::ndim_vec_3d::<i32>(&[2, 1, 3], false)).unwrap();
+
+    let squeezed = tensor_2x1x3.squeeze(1).unwrap();
+    assert_eq!(squeezed.shape(), vec![2, 3]);
+    assert_eq!(squeezed, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+    assert!(matches!(
+        tensor_2x1x3.squeeze(0),
+        Err(Error::InvalidParam { .. })
+    ));
+    assert!(matches!(
+        tensor_2x1x3.squeeze(3),
+        Err(Error::InvalidAxis { .. })
+    ));
+
+    let unsqueezed = squeezed.unsqueeze(0).unwrap();
+    assert_eq!(unsqueezed.shape(), vec![1, 2, 3]);
+    assert_eq!(unsqueezed, vec![vec![vec![1, 2, 3], vec![4, 5, 6]]]);
+
+    let unsqueezed = squeezed.expand_dims(2).unwrap();
+    assert_eq!(unsqueezed.shape(), vec![2, 3, 1]);
+    assert_eq!(
+        unsqueezed,
+        vec![
+            vec![vec![1], vec![2], vec![3]],
+            vec![vec![4], vec![5], vec![6]]
+        ]
+    );
+
+    assert!(matches!(
+        squeezed.unsqueeze(3),
+        Err(Error::InvalidAxis { .. })
+    ));
+
+    let mut tensor_2x1x3 =
+        Tensor::from_vec(ndim_vec::ndim_vec_3d::<i32>(&[2, 1, 3], false)).unwrap();
+    let mut squeezed = tensor_2x1x3.squeeze_mut(1).unwrap();
+    let mut unsqueezed = squeezed.unsqueeze_mut(0).unwrap();
+    unsqueezed.setval(&[0, 1, 2], 0).unwrap();
+    assert_eq!(unsqueezed.data(), vec![1, 2, 3, 4, 5, 0]);
+}
+
+#[test]
+fn contiguous() {
+    let tensor_2x3x4 = Tensor::from_vec(ndim_vec::ndim_vec_3d::<i32>(&[2, 3, 4], false)).unwrap();
+    assert!(tensor_2x3x4.is_contiguous());
+    assert_eq!(tensor_2x3x4.nelems(), 24);
+
+    /* Sub-tensors are contiguous, but only hold part of the buffer */
+    let slice_1 = tensor_2x3x4.slice(&[1]).unwrap();
+    assert!(slice_1.is_contiguous());
+    assert_eq!(slice_1.nelems(), 12);
+    assert_eq!(slice_1.data(), (13..=24).collect::<Vec<_>>());
+
+    let tensor_t = tensor_2x3x4.permute(&[0, 2, 1]).unwrap();
+    assert!(!tensor_t.is_contiguous());
+    assert_eq!(tensor_t.nelems(), 24);
+
+    /* Size-1 axes don't affect contiguity */
+    let tensor_1x3 = Tensor::from_vec(vec![vec![1, 2, 3]]).unwrap();
+    assert!(tensor_1x3.transpose().unwrap().is_contiguous());
+    assert!(tensor_1x3.unsqueeze(2).unwrap().is_contiguous());
+
+    /* Copies are compact and in logical order */
+    let owned = tensor_t.to_owned();
+    assert!(owned.is_contiguous());
+    assert_eq!(owned.strides(), vec![12, 3, 1]);
+    assert_eq!(owned, tensor_t);
+    assert_eq!(owned.slice(&[0, 1]).unwrap().data(), vec![2, 6, 10]);
+
+    let owned = slice_1.to_owned();
+    assert_eq!(owned.data(), slice_1.data());
+    assert_eq!(owned.shape(), vec![3, 4]);
+
+    let cow = slice_1.to_contiguous();
+    assert_eq!(cow.strides(), slice_1.strides());
+    assert_eq!(cow, slice_1);
+
+    let cow = tensor_t.to_contiguous();
+    assert!(cow.is_contiguous());
+    assert_eq!(cow, tensor_t);
+
+    /* Equality compares elements, not the backing buffers */
+    #[rustfmt::skip]
+    let tensor_2x4x3 = Tensor::from_vec(vec![
+        vec![vec![1, 5, 9], vec![2, 6, 10], vec![3, 7, 11], vec![4, 8, 12]],
+        vec![vec![13, 17, 21], vec![14, 18, 22], vec![15, 19, 23], vec![16, 20, 24]],
+    ])
+    .unwrap();
+    assert_eq!(tensor_t, tensor_2x4x3);
+    assert_eq!(tensor_2x4x3, tensor_t);
+    assert_ne!(tensor_2x3x4, tensor_2x4x3);
 }
 
 #[test]
@@ -559,7 +961,7 @@ fn iter() {
         data.push(val)
     }
     assert_eq!(
-        Tensor::from_shape(&tensor_5x4x3x2.shape(), &data).unwrap(),
+        Tensor::from_shape(&tensor_5x4x3x2.shape(), data).unwrap(),
         tensor_5x4x3x2
     );
 
@@ -571,7 +973,7 @@ fn iter() {
         data.push(val)
     }
     assert_eq!(
-        Tensor::from_shape(&tensor_5x1x3x1x2.shape(), &data).unwrap(),
+        Tensor::from_shape(&tensor_5x1x3x1x2.shape(), data).unwrap(),
         tensor_5x1x3x1x2
     );
 }
@@ -588,7 +990,7 @@ fn iter_mut() {
     }
 
     assert_eq!(
-        Tensor::from_shape(&tensor_5x4x3x2.shape(), &data).unwrap(),
+        Tensor::from_shape(&tensor_5x4x3x2.shape(), data).unwrap(),
         tensor_5x4x3x2
     );
 
@@ -602,7 +1004,7 @@ fn iter_mut() {
     }
 
     assert_eq!(
-        Tensor::from_shape(&tensor_5x1x3x1x2.shape(), &data).unwrap(),
+        Tensor::from_shape(&tensor_5x1x3x1x2.shape(), data.clone()).unwrap(),
         tensor_5x1x3x1x2
     );
 
@@ -612,9 +1014,8 @@ fn iter_mut() {
     }
 
     let data: Vec<u8> = data.iter().map(|x| x + 1).collect();
-        assert_eq!(
-        Tensor::from_shape(&tensor_5x1x3x1x2.shape(), &data).unwrap(),
+    assert_eq!(
+        Tensor::from_shape(&tensor_5x1x3x1x2.shape(), data).unwrap(),
         tensor_5x1x3x1x2
     );
-
 }
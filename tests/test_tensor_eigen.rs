@@ -0,0 +1,276 @@
+use assert_close::assert_close;
+use rs_math::tensor::{Complex, Error, Tensor};
+
+#[path = "utils/assert_close.rs"]
+mod assert_close;
+
+#[test]
+fn eigh() {
+    let tensor_a = Tensor::from_vec(vec![vec![2.0, 1.0], vec![1.0, 2.0]]).unwrap();
+
+    let (w, v) = tensor_a.eigh().unwrap();
+    assert_close(&w, vec![1.0, 3.0]);
+    assert_close(
+        &tensor_a.matmul(&v.view()).unwrap(),
+        v.zip_with(&w.view(), |a, b| a * b).unwrap().data(),
+    );
+
+    let tensor_a = Tensor::from_vec(vec![
+        vec![4.0, 1.0, 0.0],
+        vec![1.0, 3.0, 1.0],
+        vec![0.0, 1.0, 2.0],
+    ])
+    .unwrap();
+    let (w, v) = tensor_a.eigh().unwrap();
+    assert_close(&w, vec![3.0 - 3f64.sqrt(), 3.0, 3.0 + 3f64.sqrt()]);
+    assert_close(
+        &v.t().unwrap().matmul(&v.view()).unwrap(),
+        Tensor::<f64>::eye(3, 3).unwrap().data(),
+    );
+    assert_close(
+        &tensor_a.matmul(&v.view()).unwrap(),
+        v.zip_with(&w.view(), |a, b| a * b).unwrap().data(),
+    );
+
+    /* Only the lower triangle is read */
+    let tensor_l = Tensor::from_vec(vec![vec![2.0, 0.0], vec![1.0, 2.0]]).unwrap();
+    assert_close(&tensor_l.eigh().unwrap().0, vec![1.0, 3.0]);
+
+    let tensor_b = Tensor::from_vec(vec![
+        vec![vec![1.0, 0.0], vec![0.0, 1.0]],
+        vec![vec![f64::NAN, 0.0], vec![0.0, 1.0]],
+    ])
+    .unwrap();
+    assert!(matches!(
+        tensor_b.eigh(),
+        Err(Error::NoConvergence { ref index }) if *index == vec![1]
+    ));
+}
+
+fn assert_eigpairs(tensor_a: &Tensor<f64>, w: &Tensor<Complex<f64>>, v: &Tensor<Complex<f64>>) {
+    let n = w.shape()[0];
+    for col in 0..n {
+        let lambda = w.getval(&[col]).unwrap();
+        for row in 0..n {
+            let mut av = Complex::new(0.0, 0.0);
+            for j in 0..n {
+                av += v.getval(&[j, col]).unwrap() * tensor_a.getval(&[row, j]).unwrap();
+            }
+            let diff = av - lambda * v.getval(&[row, col]).unwrap();
+            assert!(diff.norm() < 1e-9, "{:?} {:?}", w, v);
+        }
+    }
+}
+
+#[test]
+fn eig() {
+    /* Rotation by 90 degrees */
+    let tensor_a = Tensor::from_vec(vec![vec![0.0, -1.0], vec![1.0, 0.0]]).unwrap();
+    let (w, v) = tensor_a.eig().unwrap();
+    assert_eq!(w.shape(), vec![2]);
+    assert_eq!(v.shape(), vec![2, 2]);
+    assert!((w.getval(&[0]).unwrap() - Complex::new(0.0, 1.0)).norm() < 1e-12);
+    assert!((w.getval(&[1]).unwrap() - Complex::new(0.0, -1.0)).norm() < 1e-12);
+    assert_eigpairs(&tensor_a, &w, &v);
+
+    let tensor_a = Tensor::from_vec(vec![
+        vec![1.0, 2.0, 3.0],
+        vec![0.0, 4.0, 5.0],
+        vec![0.0, 0.0, 6.0],
+    ])
+    .unwrap();
+    let (w, v) = tensor_a.eig().unwrap();
+    let mut re: Vec<f64> = w.iter().map(|val| val.re).collect();
+    re.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_close(&Tensor::from_vec(re).unwrap(), vec![1.0, 4.0, 6.0]);
+    assert!(w.iter().all(|val| val.im == 0.0));
+    assert_eigpairs(&tensor_a, &w, &v);
+
+    /* Mixed real and complex eigenvalues */
+    let tensor_a = Tensor::from_vec(vec![
+        vec![1.0, -2.0, 0.5, 3.0],
+        vec![2.0, 1.0, 0.0, -1.0],
+        vec![0.0, 1.5, -1.0, 2.0],
+        vec![1.0, 0.0, 2.0, 0.5],
+    ])
+    .unwrap();
+    let (w, v) = tensor_a.eig().unwrap();
+    assert_eigpairs(&tensor_a, &w, &v);
+
+    let trace = w.iter().fold(Complex::new(0.0, 0.0), |acc, val| acc + val);
+    assert!((trace - Complex::new(1.5, 0.0)).norm() < 1e-10);
+    let det = w.iter().fold(Complex::new(1.0, 0.0), |acc, val| acc * val);
+    assert!((det.re - tensor_a.det().unwrap().getval(&[]).unwrap()).abs() < 1e-9);
+
+    /* Cyclic permutations stall the standard shifts */
+    let n = 6;
+    let data: Vec<f64> = (0..n * n)
+        .map(|pos| {
+            if pos / n == (pos % n + 1) % n {
+                1.0
+            } else {
+                0.0
+            }
+        })
+        .collect();
+    let tensor_a = Tensor::from_shape(&[n, n], data).unwrap();
+    let (w, v) = tensor_a.eig().unwrap();
+    assert!(w.iter().all(|val| (val.norm() - 1.0).abs() < 1e-10));
+    assert_eigpairs(&tensor_a, &w, &v);
+}
+
+/// Determinant of the real parts of the eigenvectors, which are real for
+/// real eigenvalues. Nonzero when they are linearly independent.
+fn det_re(v: &Tensor<Complex<f64>>) -> f64 {
+    assert!(v.iter().all(|val| val.im.abs() < 1e-12));
+    let re = Tensor::from_shape(&v.shape(), v.iter().map(|val| val.re).collect()).unwrap();
+    re.det().unwrap().getval(&[]).unwrap()
+}
+
+#[test]
+fn eig_repeated() {
+    /* Every vector is an eigenvector of the identity */
+    let tensor_i = Tensor::<f64>::eye(2, 2).unwrap();
+    let (w, v) = tensor_i.eig().unwrap();
+    assert!(w
+        .iter()
+        .all(|val| (val - Complex::new(1.0, 0.0)).norm() < 1e-12));
+    assert_eigpairs(&tensor_i, &w, &v);
+    assert!(det_re(&v).abs() > 0.5);
+
+    let tensor_i = Tensor::<f64>::eye(4, 4).unwrap();
+    let (w, v) = tensor_i.eig().unwrap();
+    assert_eigpairs(&tensor_i, &w, &v);
+    assert!(det_re(&v).abs() > 0.5);
+
+    let tensor_d = Tensor::from_vec(vec![
+        vec![2.0, 0.0, 0.0],
+        vec![0.0, 3.0, 0.0],
+        vec![0.0, 0.0, 2.0],
+    ])
+    .unwrap();
+    let (w, v) = tensor_d.eig().unwrap();
+    assert_eigpairs(&tensor_d, &w, &v);
+    assert!(det_re(&v).abs() > 0.5);
+
+    /* Diagonalizable but not normal: P * diag(1, 1, 2) * P^-1 */
+    let tensor_p = Tensor::from_vec(vec![
+        vec![1.0, 1.0, 0.0],
+        vec![0.0, 1.0, 1.0],
+        vec![1.0, 0.0, 2.0],
+    ])
+    .unwrap();
+    let tensor_pd = tensor_p
+        .zip_with(
+            &Tensor::from_vec(vec![1.0, 1.0, 2.0]).unwrap().view(),
+            |a, b| a * b,
+        )
+        .unwrap();
+    let tensor_a = tensor_pd.matmul(&tensor_p.inv().unwrap().view()).unwrap();
+    let (w, v) = tensor_a.eig().unwrap();
+    assert_eigpairs(&tensor_a, &w, &v);
+    assert!(det_re(&v).abs() > 1e-3);
+
+    /* A defective matrix has a single eigenvector for its double eigenvalue */
+    let tensor_j = Tensor::from_vec(vec![vec![1.0, 1.0], vec![0.0, 1.0]]).unwrap();
+    let (w, v) = tensor_j.eig().unwrap();
+    assert_eigpairs(&tensor_j, &w, &v);
+
+    /* The all ones start already is the eigenvector of this Jordan block */
+    let tensor_j = Tensor::from_vec(vec![vec![0.0, 1.0], vec![-1.0, 2.0]]).unwrap();
+    let (w, v) = tensor_j.eig().unwrap();
+    assert_eigpairs(&tensor_j, &w, &v);
+}
+
+#[test]
+fn svd() {
+    let tensor_a = Tensor::from_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]).unwrap();
+
+    let (u, s, vt) = tensor_a.svd(false).unwrap();
+    assert_eq!(
+        (u.shape(), s.shape(), vt.shape()),
+        (vec![3, 2], vec![2], vec![2, 2])
+    );
+    assert!(s.getval(&[0]).unwrap() > s.getval(&[1]).unwrap());
+    let us = u.zip_with(&s.view(), |a, b| a * b).unwrap();
+    assert_close(&us.matmul(&vt.view()).unwrap(), tensor_a.data());
+
+    let (u, _, vt) = tensor_a.svd(true).unwrap();
+    assert_eq!((u.shape(), vt.shape()), (vec![3, 3], vec![2, 2]));
+    assert_close(
+        &u.t().unwrap().matmul(&u.view()).unwrap(),
+        Tensor::<f64>::eye(3, 3).unwrap().data(),
+    );
+
+    /* Wide matrices go through the transpose */
+    let tensor_w = tensor_a.t().unwrap().to_owned();
+    let (u, s, vt) = tensor_w.svd(true).unwrap();
+    assert_eq!(
+        (u.shape(), s.shape(), vt.shape()),
+        (vec![2, 2], vec![2], vec![3, 3])
+    );
+    let (_, s_thin, vt_thin) = tensor_w.svd(false).unwrap();
+    assert_eq!(vt_thin.shape(), vec![2, 3]);
+    assert_close(&s, s_thin.data());
+    let us = u.zip_with(&s.view(), |a, b| a * b).unwrap();
+    assert_close(&us.matmul(&vt_thin.view()).unwrap(), tensor_w.data());
+    assert_close(
+        &vt.matmul(&vt.t().unwrap()).unwrap(),
+        Tensor::<f64>::eye(3, 3).unwrap().data(),
+    );
+
+    /* Zero singular values still give orthonormal factors */
+    let tensor_s = Tensor::from_vec(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+    let (u, s, _) = tensor_s.svd(false).unwrap();
+    assert_close(&s, vec![5.0, 0.0]);
+    assert_close(
+        &u.t().unwrap().matmul(&u.view()).unwrap(),
+        vec![1.0, 0.0, 0.0, 1.0],
+    );
+
+    /* Batched */
+    let tensor_b = Tensor::from_vec(vec![
+        vec![vec![3.0, 0.0], vec![4.0, 5.0]],
+        vec![vec![2.0, 0.0], vec![0.0, -1.0]],
+    ])
+    .unwrap();
+    let (_, s, _) = tensor_b.svd(false).unwrap();
+    assert_eq!(s.shape(), vec![2, 2]);
+    assert_close(&s, vec![45f64.sqrt(), 5f64.sqrt(), 2.0, 1.0]);
+}
+
+#[test]
+fn pinv() {
+    let tensor_a = Tensor::from_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]).unwrap();
+
+    let tensor_p = tensor_a.pinv().unwrap();
+    assert_eq!(tensor_p.shape(), vec![2, 3]);
+    assert_close(
+        &tensor_p.matmul(&tensor_a.view()).unwrap(),
+        vec![1.0, 0.0, 0.0, 1.0],
+    );
+
+    let tensor_s = Tensor::from_vec(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+    assert_close(&tensor_s.pinv().unwrap(), vec![0.04, 0.08, 0.08, 0.16]);
+}
+
+#[test]
+fn rank_cond_norms() {
+    let tensor_b = Tensor::from_vec(vec![
+        vec![vec![3.0f64, 0.0], vec![4.0, 5.0]],
+        vec![vec![1.0, 2.0], vec![2.0, 4.0]],
+    ])
+    .unwrap();
+
+    assert_eq!(tensor_b.matrix_rank().unwrap(), vec![2, 1]);
+
+    let cond = tensor_b.cond().unwrap();
+    assert!((cond.getval(&[0]).unwrap() - 3.0).abs() < 1e-10);
+    assert!(cond.getval(&[1]).unwrap() > 1e10);
+
+    assert_close(&tensor_b.norm_nuc().unwrap(), vec![4.0 * 5f64.sqrt(), 5.0]);
+    assert_close(
+        &tensor_b.norm_spectral().unwrap(),
+        vec![3.0 * 5f64.sqrt(), 5.0],
+    );
+}
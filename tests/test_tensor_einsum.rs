@@ -0,0 +1,246 @@
+use rs_math::tensor::{Error, Tensor};
+
+fn arange(shape: &[usize]) -> Tensor<'static, i64> {
+    let len = shape.iter().product::<usize>() as i64;
+    Tensor::from_shape(shape, (1..=len).collect()).unwrap()
+}
+
+#[test]
+fn einsum() {
+    let tensor_a = arange(&[2, 3, 4]);
+    let tensor_b = arange(&[2, 4, 2]);
+
+    /* Batched matrix product */
+    let tensor_c = Tensor::einsum("bij,bjk->bik", &[tensor_a.view(), tensor_b.view()]).unwrap();
+    assert_eq!(tensor_c, tensor_a.matmul(&tensor_b.view()).unwrap());
+
+    /* Implicit output sorts the labels appearing once */
+    let tensor_c = Tensor::einsum("bij,bjk", &[tensor_a.view(), tensor_b.view()]).unwrap();
+    assert_eq!(tensor_c.shape(), vec![3, 2]);
+    let tensor_d = Tensor::einsum("bij,bjk->ik", &[tensor_a.view(), tensor_b.view()]).unwrap();
+    assert_eq!(tensor_c, tensor_d);
+
+    /* Transpose, total sum and sum over an axis */
+    let tensor_m = arange(&[2, 3]);
+    let tensor_t = Tensor::einsum("ij->ji", &[tensor_m.view()]).unwrap();
+    assert_eq!(tensor_t, tensor_m.t().unwrap().to_owned());
+    let tensor_s = Tensor::einsum("ij->", &[tensor_m.view()]).unwrap();
+    assert_eq!(tensor_s.shape(), vec![]);
+    assert_eq!(tensor_s.data(), vec![21]);
+    let tensor_s = Tensor::einsum("ij->j", &[tensor_m.view()]).unwrap();
+    assert_eq!(tensor_s.data(), vec![5, 7, 9]);
+
+    /* Outer product of vectors and elementwise product */
+    let tensor_u = Tensor::from_vec(vec![1, 2]).unwrap();
+    let tensor_v = Tensor::from_vec(vec![3, 4, 5]).unwrap();
+    let tensor_o = Tensor::einsum("i,j", &[tensor_u.view(), tensor_v.view()]).unwrap();
+    assert_eq!(tensor_o.data(), vec![3, 4, 5, 6, 8, 10]);
+    let tensor_p = Tensor::einsum("i,i->i", &[tensor_v.view(), tensor_v.view()]).unwrap();
+    assert_eq!(tensor_p.data(), vec![9, 16, 25]);
+}
+
+#[test]
+fn einsum_repeated() {
+    let tensor_a = arange(&[3, 3]);
+
+    /* Trace and diagonal */
+    let tensor_tr = Tensor::einsum("ii", &[tensor_a.view()]).unwrap();
+    assert_eq!(tensor_tr.data(), vec![15]);
+    let tensor_diag = Tensor::einsum("ii->i", &[tensor_a.view()]).unwrap();
+    assert_eq!(tensor_diag.data(), vec![1, 5, 9]);
+
+    /* Batched traces */
+    let tensor_b = arange(&[2, 2, 2]);
+    let tensor_tr = Tensor::einsum("bii->b", &[tensor_b.view()]).unwrap();
+    assert_eq!(tensor_tr.data(), vec![5, 13]);
+
+    /* Repeated axes must have the same dimension */
+    let tensor_r = arange(&[2, 3]);
+    assert!(matches!(
+        Tensor::einsum("ii", &[tensor_r.view()]),
+        Err(Error::InvalidParam { .. })
+    ));
+}
+
+#[test]
+fn einsum_ellipsis() {
+    let tensor_a = arange(&[2, 3, 4]);
+    let tensor_b = arange(&[4, 2]);
+
+    /* The ellipsis broadcasts like matmul */
+    let tensor_c =
+        Tensor::einsum("...ij,...jk->...ik", &[tensor_a.view(), tensor_b.view()]).unwrap();
+    assert_eq!(tensor_c, tensor_a.matmul(&tensor_b.view()).unwrap());
+    let tensor_d = Tensor::einsum("...ij,jk", &[tensor_a.view(), tensor_b.view()]).unwrap();
+    assert_eq!(tensor_c, tensor_d);
+
+    /* Dimensions of size 1 broadcast */
+    let tensor_x = arange(&[2, 1, 3]);
+    let tensor_y = arange(&[4, 3]);
+    let tensor_z = Tensor::einsum("...i,...i->...", &[tensor_x.view(), tensor_y.view()]).unwrap();
+    assert_eq!(tensor_z.shape(), vec![2, 4]);
+    assert_eq!(tensor_z.getval(&[1, 2]).unwrap(), 4 * 7 + 5 * 8 + 6 * 9);
+
+    let tensor_s = Tensor::einsum("i...->...", &[tensor_a.view()]).unwrap();
+    assert_eq!(tensor_s.shape(), vec![3, 4]);
+    assert_eq!(tensor_s.getval(&[0, 0]).unwrap(), 1 + 13);
+
+    assert!(matches!(
+        Tensor::einsum("...ij,...jk->ik", &[tensor_a.view(), tensor_b.view()]),
+        Err(Error::InvalidParam { .. })
+    ));
+}
+
+#[test]
+fn einsum_views() {
+    let tensor_a = arange(&[2, 3, 4]);
+    let tensor_b = arange(&[3, 4]);
+
+    /* Transposed operands aren't contiguous */
+    let tensor_at = tensor_a.t().unwrap();
+    let tensor_bt = tensor_b.t().unwrap();
+    let tensor_c = Tensor::einsum("kji,kj->i", &[tensor_at, tensor_bt]).unwrap();
+    let expected = Tensor::einsum("ijk,jk->i", &[tensor_a.view(), tensor_b.view()]).unwrap();
+    assert_eq!(tensor_c, expected);
+
+    /* Slices start at an offset */
+    let tensor_s = tensor_a.slice(&[1]).unwrap();
+    let tensor_c = Tensor::einsum("ij,ij->", &[tensor_s, tensor_b.view()]).unwrap();
+    let sum = (0..12).map(|i| (13 + i) * (1 + i)).sum::<i64>();
+    assert_eq!(tensor_c.data(), vec![sum]);
+}
+
+#[test]
+fn einsum_errors() {
+    let tensor_a = arange(&[2, 3]);
+
+    assert!(matches!(
+        Tensor::einsum("ij,jk", &[tensor_a.view()]),
+        Err(Error::InvalidParam { .. })
+    ));
+    assert!(matches!(
+        Tensor::einsum("ijk", &[tensor_a.view()]),
+        Err(Error::DimensionMismatch { .. })
+    ));
+    assert!(matches!(
+        Tensor::einsum("i1", &[tensor_a.view()]),
+        Err(Error::InvalidParam { .. })
+    ));
+    assert!(matches!(
+        Tensor::einsum("ij->k", &[tensor_a.view()]),
+        Err(Error::InvalidParam { .. })
+    ));
+    assert!(matches!(
+        Tensor::einsum("ij,ij", &[tensor_a.view(), arange(&[3, 2]).view()]),
+        Err(Error::ShapeMismatch { .. })
+    ));
+}
+
+#[test]
+fn tensordot() {
+    let tensor_a = arange(&[3, 4, 5]);
+    let tensor_b = arange(&[4, 3, 2]);
+
+    let tensor_c = tensor_a
+        .tensordot(&tensor_b.view(), &[1, 0], &[0, 1])
+        .unwrap();
+    let expected = Tensor::einsum("ijk,jil->kl", &[tensor_a.view(), tensor_b.view()]).unwrap();
+    assert_eq!(tensor_c.shape(), vec![5, 2]);
+    assert_eq!(tensor_c, expected);
+
+    /* No axes gives the outer product */
+    let tensor_m = arange(&[2, 2]);
+    let tensor_c = tensor_m.tensordot(&tensor_m.view(), &[], &[]).unwrap();
+    assert_eq!(tensor_c.shape(), vec![2, 2, 2, 2]);
+
+    assert!(matches!(
+        tensor_a.tensordot(&tensor_b.view(), &[0], &[0]),
+        Err(Error::ShapeMismatch { .. })
+    ));
+    assert!(matches!(
+        tensor_a.tensordot(&tensor_b.view(), &[3], &[0]),
+        Err(Error::InvalidAxis { axis: 3, ndim: 3 })
+    ));
+    assert!(matches!(
+        tensor_a.tensordot(&tensor_b.view(), &[0, 1], &[1]),
+        Err(Error::InvalidParam { .. })
+    ));
+}
+
+#[test]
+fn dot_inner_outer() {
+    let tensor_a = arange(&[2, 3]);
+    let tensor_b = arange(&[3, 2]);
+    let tensor_v = Tensor::from_vec(vec![1, 0, 2]).unwrap();
+
+    assert_eq!(
+        tensor_a.dot(&tensor_b.view()).unwrap(),
+        tensor_a.matmul(&tensor_b.view()).unwrap()
+    );
+    assert_eq!(tensor_a.dot(&tensor_v.view()).unwrap().data(), vec![7, 16]);
+    assert_eq!(tensor_v.dot(&tensor_v.view()).unwrap().data(), vec![5]);
+
+    /* The last axis of a against the second to last of b */
+    let tensor_c = arange(&[2, 3, 2]);
+    let tensor_d = tensor_a.dot(&tensor_c.view()).unwrap();
+    assert_eq!(tensor_d.shape(), vec![2, 2, 2]);
+    assert_eq!(
+        tensor_d.slice(&[0, 1]).unwrap().to_owned(),
+        tensor_a
+            .matmul(&tensor_c.slice(&[1]).unwrap())
+            .unwrap()
+            .slice(&[0])
+            .unwrap()
+            .to_owned()
+    );
+
+    let scalar = Tensor::from_shape(&[], vec![2]).unwrap();
+    assert_eq!(
+        tensor_a.dot(&scalar.view()).unwrap().data(),
+        vec![2, 4, 6, 8, 10, 12]
+    );
+
+    /* inner contracts the last axes of both */
+    let tensor_i = tensor_a.inner(&tensor_a.view()).unwrap();
+    assert_eq!(tensor_i.data(), vec![14, 32, 32, 77]);
+    assert_eq!(
+        tensor_a.inner(&tensor_v.view()).unwrap(),
+        tensor_a.dot(&tensor_v.view()).unwrap()
+    );
+
+    /* outer flattens its inputs */
+    let tensor_o = tensor_a.outer(&tensor_v.view()).unwrap();
+    assert_eq!(tensor_o.shape(), vec![6, 3]);
+    assert_eq!(tensor_o.getval(&[4, 2]).unwrap(), 10);
+    let tensor_o = tensor_a.t().unwrap().outer(&tensor_v.view()).unwrap();
+    assert_eq!(tensor_o.getval(&[1, 2]).unwrap(), 8);
+}
+
+#[test]
+fn kron() {
+    let tensor_a = Tensor::from_vec(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    let tensor_i = Tensor::<i64>::eye(2, 2).unwrap();
+
+    let tensor_k = tensor_a.kron(&tensor_i.view()).unwrap();
+    assert_eq!(
+        tensor_k,
+        Tensor::from_vec(vec![
+            vec![1, 0, 2, 0],
+            vec![0, 1, 0, 2],
+            vec![3, 0, 4, 0],
+            vec![0, 3, 0, 4],
+        ])
+        .unwrap()
+    );
+
+    /* The tensor with fewer dimensions is padded */
+    let tensor_v = Tensor::from_vec(vec![1, 10]).unwrap();
+    let tensor_k = tensor_a.kron(&tensor_v.view()).unwrap();
+    assert_eq!(
+        tensor_k,
+        Tensor::from_vec(vec![vec![1, 10, 2, 20], vec![3, 30, 4, 40]]).unwrap()
+    );
+    let tensor_k = tensor_v.kron(&tensor_a.t().unwrap()).unwrap();
+    assert_eq!(tensor_k.shape(), vec![2, 4]);
+    assert_eq!(tensor_k.data(), vec![1, 3, 10, 30, 2, 4, 20, 40]);
+}
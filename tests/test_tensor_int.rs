@@ -0,0 +1,88 @@
+use rs_math::tensor::{Error, Tensor};
+
+#[test]
+fn checked() {
+    let tensor_a = Tensor::from_vec(vec![vec![100u8, 200], vec![50, 255]]).unwrap();
+    let tensor_b = Tensor::from_vec(vec![vec![100u8, 50], vec![1, 1]]).unwrap();
+
+    assert_eq!(
+        tensor_a.checked_sub(&tensor_b.view()).unwrap(),
+        vec![vec![0, 150], vec![49, 254]]
+    );
+    assert!(matches!(
+        tensor_a.checked_add(&tensor_b.view()),
+        Err(Error::Overflow { ref index }) if *index == vec![1, 1]
+    ));
+    assert!(matches!(
+        tensor_b.checked_sub(&tensor_a.view()),
+        Err(Error::Overflow { ref index }) if *index == vec![0, 1]
+    ));
+
+    /* The index refers to the broadcast result */
+    let row = Tensor::from_vec(vec![1u8, 3]).unwrap();
+    assert!(matches!(
+        tensor_a.checked_mul(&row.view()),
+        Err(Error::Overflow { ref index }) if *index == vec![0, 1]
+    ));
+
+    let tensor = Tensor::from_vec(vec![-100i8, 0, 100]).unwrap();
+    assert_eq!(tensor.checked_add_scalar(27).unwrap(), vec![-73, 27, 127]);
+    assert!(matches!(
+        tensor.checked_mul_scalar(2),
+        Err(Error::Overflow { ref index }) if *index == vec![0]
+    ));
+}
+
+#[test]
+fn saturating() {
+    let tensor = Tensor::from_vec(vec![vec![10u8, 200], vec![250, 0]]).unwrap();
+    let other = Tensor::from_vec(vec![vec![250u8, 100], vec![10, 1]]).unwrap();
+
+    assert_eq!(
+        tensor.saturating_add(&other.view()).unwrap(),
+        vec![vec![255, 255], vec![255, 1]]
+    );
+    assert_eq!(
+        tensor.saturating_sub(&other.view()).unwrap(),
+        vec![vec![0, 100], vec![240, 0]]
+    );
+    assert_eq!(
+        tensor.saturating_mul_scalar(2),
+        vec![vec![20, 255], vec![255, 0]]
+    );
+
+    let tensor = Tensor::from_vec(vec![i32::MIN, -1, i32::MAX]).unwrap();
+    assert_eq!(
+        tensor.saturating_sub_scalar(1),
+        vec![i32::MIN, -2, i32::MAX - 1]
+    );
+    assert_eq!(
+        tensor.saturating_mul_scalar(-1),
+        vec![i32::MAX, 1, -i32::MAX]
+    );
+}
+
+#[test]
+fn wrapping() {
+    let tensor = Tensor::from_vec(vec![vec![250u8, 5], vec![128, 0]]).unwrap();
+
+    assert_eq!(
+        tensor.wrapping_add_scalar(10),
+        vec![vec![4, 15], vec![138, 10]]
+    );
+    assert_eq!(
+        tensor.wrapping_sub_scalar(10),
+        vec![vec![240, 251], vec![118, 246]]
+    );
+    assert_eq!(
+        tensor.wrapping_mul(&tensor.view()).unwrap(),
+        vec![vec![36, 25], vec![0, 0]]
+    );
+
+    /* Views wrap their own elements only */
+    let view_t = tensor.transpose().unwrap();
+    assert_eq!(
+        view_t.wrapping_add(&tensor.view()).unwrap(),
+        vec![vec![244, 133], vec![133, 0]]
+    );
+}
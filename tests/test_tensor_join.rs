@@ -0,0 +1,117 @@
+use rs_math::tensor::{Error, Tensor};
+
+#[test]
+fn concat() {
+    let tensor_a = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    let tensor_b = Tensor::from_vec(vec![vec![7, 8, 9]]).unwrap();
+    let tensor_c = Tensor::from_vec(vec![vec![10], vec![11]]).unwrap();
+
+    let tensor_r = Tensor::concat(&[tensor_a.view(), tensor_b.view()], 0).unwrap();
+    assert_eq!(tensor_r.shape(), vec![3, 3]);
+    assert_eq!(tensor_r, vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+
+    let tensor_r = Tensor::concat(&[tensor_a.view(), tensor_c.view()], 1).unwrap();
+    assert_eq!(tensor_r, vec![vec![1, 2, 3, 10], vec![4, 5, 6, 11]]);
+
+    /* Non-contiguous views */
+    let tensor_t = tensor_a.transpose().unwrap();
+    let tensor_r = Tensor::concat(&[tensor_t, tensor_b.transpose().unwrap()], 1).unwrap();
+    assert_eq!(tensor_r, vec![vec![1, 4, 7], vec![2, 5, 8], vec![3, 6, 9]]);
+
+    assert!(matches!(
+        Tensor::concat(&[tensor_a.view(), tensor_c.view()], 0),
+        Err(Error::ShapeMismatch { .. })
+    ));
+    assert!(matches!(
+        Tensor::concat(&[tensor_a.view(), tensor_b.view()], 2),
+        Err(Error::InvalidAxis { .. })
+    ));
+    assert!(matches!(
+        Tensor::<i32>::concat(&[], 0),
+        Err(Error::InvalidParam { .. })
+    ));
+}
+
+#[test]
+fn stack() {
+    let tensor_a = Tensor::from_vec(vec![1, 2, 3]).unwrap();
+    let tensor_b = Tensor::from_vec(vec![4, 5, 6]).unwrap();
+
+    let tensor_r = Tensor::stack(&[tensor_a.view(), tensor_b.view()], 0).unwrap();
+    assert_eq!(tensor_r.shape(), vec![2, 3]);
+    assert_eq!(tensor_r, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+    let tensor_r = Tensor::stack(&[tensor_a.view(), tensor_b.view()], 1).unwrap();
+    assert_eq!(tensor_r.shape(), vec![3, 2]);
+    assert_eq!(tensor_r, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+
+    let tensor_c = Tensor::from_vec(vec![7, 8]).unwrap();
+    assert!(matches!(
+        Tensor::stack(&[tensor_a.view(), tensor_c.view()], 0),
+        Err(Error::ShapeMismatch { .. })
+    ));
+    assert!(matches!(
+        Tensor::stack(&[tensor_a.view(), tensor_b.view()], 2),
+        Err(Error::InvalidAxis { .. })
+    ));
+}
+
+#[test]
+fn split() {
+    let tensor_5x2 = Tensor::from_vec(vec![
+        vec![1, 2],
+        vec![3, 4],
+        vec![5, 6],
+        vec![7, 8],
+        vec![9, 10],
+    ])
+    .unwrap();
+
+    let parts = tensor_5x2.split(2, 0).unwrap();
+    assert_eq!(parts.len(), 3);
+    assert_eq!(parts[0], vec![vec![1, 2], vec![3, 4]]);
+    assert_eq!(parts[1], vec![vec![5, 6], vec![7, 8]]);
+    assert_eq!(parts[2].shape(), vec![1, 2]);
+    assert_eq!(parts[2].getval(&[0, 1]).unwrap(), 10);
+
+    let parts = tensor_5x2.split(1, 1).unwrap();
+    assert_eq!(parts.len(), 2);
+    assert_eq!(parts[1].shape(), vec![5, 1]);
+    assert_eq!(parts[1].getval(&[3, 0]).unwrap(), 8);
+
+    /* Splitting and joining again gives back the tensor */
+    let parts = tensor_5x2.split_sizes(&[1, 4], 0).unwrap();
+    assert_eq!(parts[1].shape(), vec![4, 2]);
+    assert_eq!(Tensor::concat(&parts, 0).unwrap(), tensor_5x2);
+
+    assert!(matches!(
+        tensor_5x2.split_sizes(&[1, 2], 0),
+        Err(Error::ShapeMismatch { .. })
+    ));
+    assert!(matches!(
+        tensor_5x2.split(0, 0),
+        Err(Error::InvalidParam { .. })
+    ));
+    assert!(matches!(
+        tensor_5x2.split(1, 2),
+        Err(Error::InvalidAxis { .. })
+    ));
+}
+
+#[test]
+fn chunk() {
+    let tensor = Tensor::from_vec(vec![1, 2, 3, 4, 5, 6, 7]).unwrap();
+
+    let chunks = tensor.chunk(3, 0).unwrap();
+    let shapes: Vec<_> = chunks.iter().map(|chunk| chunk.shape()).collect();
+    assert_eq!(shapes, vec![vec![3], vec![3], vec![1]]);
+    assert_eq!(chunks[1].getval(&[0]).unwrap(), 4);
+
+    let chunks = tensor.chunk(10, 0).unwrap();
+    assert_eq!(chunks.len(), 7);
+
+    assert!(matches!(
+        tensor.chunk(0, 0),
+        Err(Error::InvalidParam { .. })
+    ));
+}
@@ -1,7 +1,11 @@
+use assert_close::assert_close;
 use numpy::PyArrayDyn;
 use pyo3::prelude::*;
 use pyo3::Python;
-use rs_math::tensor::{Error, Tensor};
+use rs_math::tensor::{Error, QrMode, Tensor, Triangle};
+
+#[path = "utils/assert_close.rs"]
+mod assert_close;
 
 #[path = "utils/py_ndarray.rs"]
 mod py_ndarray;
@@ -68,3 +72,328 @@ fn softmax() {
         py_ndarray::assert_eq(&ts_2x2, py_result);
     });
 }
+
+#[test]
+fn lu() {
+    let tensor_a = Tensor::from_vec(vec![
+        vec![1.0, 2.0, 3.0],
+        vec![4.0, 5.0, 6.0],
+        vec![7.0, 8.0, 10.0],
+    ])
+    .unwrap();
+
+    let (p, l, u) = tensor_a.lu().unwrap();
+    assert_eq!(
+        p,
+        vec![
+            vec![0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 1.0],
+            vec![1.0, 0.0, 0.0]
+        ]
+    );
+    assert_eq!(l.getval(&[0, 0]).unwrap(), 1.0);
+    assert_eq!(l.getval(&[0, 2]).unwrap(), 0.0);
+    assert_eq!(u.getval(&[2, 0]).unwrap(), 0.0);
+
+    let tensor_r = p.matmul(&l.matmul(&u.view()).unwrap().view()).unwrap();
+    assert_close(&tensor_r, tensor_a.data());
+
+    /* Singular matrices are still factorized */
+    let tensor_s = Tensor::from_vec(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+    let (_, _, u) = tensor_s.lu().unwrap();
+    assert_eq!(u.getval(&[1, 1]).unwrap(), 0.0);
+
+    assert!(matches!(
+        Tensor::from_vec(vec![vec![1.0, 2.0]]).unwrap().lu(),
+        Err(Error::InvalidParam { .. })
+    ));
+    assert!(matches!(
+        Tensor::from_vec(vec![1.0, 2.0]).unwrap().lu(),
+        Err(Error::DimensionMismatch { .. })
+    ));
+}
+
+#[test]
+fn solve() {
+    let tensor_a = Tensor::from_vec(vec![vec![3.0, 1.0], vec![1.0, 2.0]]).unwrap();
+
+    let tensor_b = Tensor::from_vec(vec![9.0, 8.0]).unwrap();
+    let tensor_x = tensor_a.solve(&tensor_b.view()).unwrap();
+    assert_eq!(tensor_x.shape(), vec![2]);
+    assert_close(&tensor_x, vec![2.0, 3.0]);
+
+    /* Multiple right-hand sides */
+    let tensor_b = Tensor::from_vec(vec![vec![9.0, 1.0], vec![8.0, 2.0]]).unwrap();
+    let tensor_x = tensor_a.solve(&tensor_b.view()).unwrap();
+    assert_eq!(tensor_x.shape(), vec![2, 2]);
+    assert_close(&tensor_x, vec![2.0, 0.0, 3.0, 1.0]);
+
+    /* Batched matrices with a broadcast right-hand side */
+    let tensor_a = Tensor::from_vec(vec![
+        vec![vec![2.0, 0.0], vec![0.0, 4.0]],
+        vec![vec![0.0, 1.0], vec![1.0, 0.0]],
+    ])
+    .unwrap();
+    let tensor_b = Tensor::from_vec(vec![vec![4.0], vec![8.0]]).unwrap();
+    let tensor_x = tensor_a.solve(&tensor_b.view()).unwrap();
+    assert_eq!(tensor_x.shape(), vec![2, 2, 1]);
+    assert_close(&tensor_x, vec![2.0, 2.0, 8.0, 4.0]);
+
+    /* Non-contiguous operands */
+    let tensor_a = Tensor::from_vec(vec![vec![3.0, 1.0], vec![2.0, 2.0]]).unwrap();
+    let tensor_t = tensor_a.transpose().unwrap();
+    let tensor_x = tensor_t
+        .solve(&Tensor::from_vec(vec![7.0, 5.0]).unwrap().view())
+        .unwrap();
+    assert_close(&tensor_x, vec![1.0, 2.0]);
+
+    let tensor_s = Tensor::from_vec(vec![
+        vec![vec![1.0, 0.0], vec![0.0, 1.0]],
+        vec![vec![1.0, 2.0], vec![2.0, 4.0]],
+    ])
+    .unwrap();
+    assert!(matches!(
+        tensor_s.solve(&tensor_b.view()),
+        Err(Error::SingularMatrix { ref index }) if *index == vec![1]
+    ));
+    assert!(matches!(
+        tensor_s.solve(&Tensor::from_vec(vec![1.0, 2.0, 3.0]).unwrap().view()),
+        Err(Error::ShapeMismatch { .. })
+    ));
+}
+
+#[test]
+fn det() {
+    let tensor_a = Tensor::from_vec(vec![
+        vec![1.0f64, 2.0, 3.0],
+        vec![4.0, 5.0, 6.0],
+        vec![7.0, 8.0, 10.0],
+    ])
+    .unwrap();
+
+    let det = tensor_a.det().unwrap();
+    assert_eq!(det.shape(), Vec::<usize>::new());
+    assert!((det.getval(&[]).unwrap() + 3.0).abs() < 1e-10);
+
+    let (sign, logabsdet) = tensor_a.slogdet().unwrap();
+    assert_eq!(sign.getval(&[]).unwrap(), -1.0);
+    assert!((logabsdet.getval(&[]).unwrap() - 3.0f64.ln()).abs() < 1e-10);
+
+    /* Batched, including a singular matrix */
+    let tensor_b = Tensor::from_vec(vec![
+        vec![vec![2.0, 0.0], vec![0.0, 3.0]],
+        vec![vec![1.0, 2.0], vec![2.0, 4.0]],
+    ])
+    .unwrap();
+    assert_eq!(tensor_b.det().unwrap(), vec![6.0, 0.0]);
+
+    let (sign, logabsdet) = tensor_b.slogdet().unwrap();
+    assert_eq!(sign, vec![1.0, 0.0]);
+    assert_eq!(logabsdet.getval(&[1]).unwrap(), f64::NEG_INFINITY);
+}
+
+#[test]
+fn inv() {
+    let tensor_a = Tensor::from_vec(vec![vec![4.0, 7.0], vec![2.0, 6.0]]).unwrap();
+
+    let tensor_i = tensor_a.inv().unwrap();
+    assert_close(&tensor_i, vec![0.6, -0.7, -0.2, 0.4]);
+
+    let tensor_r = tensor_a.matmul(&tensor_i.view()).unwrap();
+    assert_close(&tensor_r, vec![1.0, 0.0, 0.0, 1.0]);
+
+    let tensor_s = Tensor::from_vec(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+    assert!(matches!(
+        tensor_s.inv(),
+        Err(Error::SingularMatrix { ref index }) if index.is_empty()
+    ));
+}
+
+#[test]
+fn qr() {
+    let tensor_a = Tensor::from_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]).unwrap();
+
+    let (q, r) = tensor_a.qr(QrMode::Reduced).unwrap();
+    assert_eq!((q.shape(), r.shape()), (vec![3, 2], vec![2, 2]));
+    assert_eq!(r.getval(&[1, 0]).unwrap(), 0.0);
+    assert_close(&q.matmul(&r.view()).unwrap(), tensor_a.data());
+    assert_close(
+        &q.t().unwrap().matmul(&q.view()).unwrap(),
+        vec![1.0, 0.0, 0.0, 1.0],
+    );
+
+    let (q, r) = tensor_a.qr(QrMode::Complete).unwrap();
+    assert_eq!((q.shape(), r.shape()), (vec![3, 3], vec![3, 2]));
+    assert_close(&q.matmul(&r.view()).unwrap(), tensor_a.data());
+    assert_close(
+        &q.t().unwrap().matmul(&q.view()).unwrap(),
+        Tensor::<f64>::eye(3, 3).unwrap().data(),
+    );
+
+    /* Wide and batched matrices */
+    let tensor_w = Tensor::from_vec(vec![
+        vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]],
+        vec![vec![0.0, 1.0, 0.0], vec![2.0, 0.0, 1.0]],
+    ])
+    .unwrap();
+    let (q, r) = tensor_w.qr(QrMode::Reduced).unwrap();
+    assert_eq!((q.shape(), r.shape()), (vec![2, 2, 2], vec![2, 2, 3]));
+    assert_close(&q.matmul(&r.view()).unwrap(), tensor_w.data());
+
+    assert!(matches!(
+        Tensor::from_vec(vec![1.0, 2.0])
+            .unwrap()
+            .qr(QrMode::Reduced),
+        Err(Error::DimensionMismatch { .. })
+    ));
+}
+
+#[test]
+fn cholesky() {
+    let tensor_a = Tensor::from_vec(vec![
+        vec![4.0, 12.0, -16.0],
+        vec![12.0, 37.0, -43.0],
+        vec![-16.0, -43.0, 98.0],
+    ])
+    .unwrap();
+
+    let tensor_l = tensor_a.cholesky().unwrap();
+    assert_close(
+        &tensor_l,
+        vec![2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0],
+    );
+
+    let tensor_b = Tensor::from_vec(vec![
+        vec![vec![1.0, 0.0], vec![0.0, 1.0]],
+        vec![vec![1.0, 2.0], vec![2.0, 1.0]],
+    ])
+    .unwrap();
+    assert!(matches!(
+        tensor_b.cholesky(),
+        Err(Error::NotPositiveDefinite { ref index }) if *index == vec![1]
+    ));
+}
+
+#[test]
+fn lstsq() {
+    /* Overdetermined line fit */
+    let tensor_a = Tensor::from_vec(vec![
+        vec![1.0, 0.0],
+        vec![1.0, 1.0],
+        vec![1.0, 2.0],
+        vec![1.0, 3.0],
+    ])
+    .unwrap();
+    let tensor_b = Tensor::from_vec(vec![1.0, 2.0, 2.0, 4.0]).unwrap();
+    let tensor_x = tensor_a.lstsq(&tensor_b.view()).unwrap();
+    assert_eq!(tensor_x.shape(), vec![2]);
+    assert_close(&tensor_x, vec![0.9, 0.9]);
+
+    /* Multiple right-hand sides, one of them exact */
+    let tensor_b = Tensor::from_vec(vec![
+        vec![1.0, 1.0],
+        vec![2.0, 3.0],
+        vec![2.0, 5.0],
+        vec![4.0, 7.0],
+    ])
+    .unwrap();
+    let tensor_x = tensor_a.lstsq(&tensor_b.view()).unwrap();
+    assert_eq!(tensor_x.shape(), vec![2, 2]);
+    assert_close(&tensor_x, vec![0.9, 1.0, 0.9, 2.0]);
+
+    /* Underdetermined systems give the minimum norm solution */
+    let tensor_w = Tensor::from_vec(vec![vec![1.0, 0.0, 1.0], vec![0.0, 1.0, 0.0]]).unwrap();
+    let tensor_x = tensor_w
+        .lstsq(&Tensor::from_vec(vec![2.0, 3.0]).unwrap().view())
+        .unwrap();
+    assert_close(&tensor_x, vec![1.0, 3.0, 1.0]);
+
+    let tensor_s = Tensor::from_vec(vec![vec![1.0, 2.0], vec![2.0, 4.0], vec![3.0, 6.0]]).unwrap();
+    assert!(matches!(
+        tensor_s.lstsq(&Tensor::from_vec(vec![1.0, 2.0, 3.0]).unwrap().view()),
+        Err(Error::SingularMatrix { .. })
+    ));
+    assert!(matches!(
+        tensor_s.lstsq(&Tensor::from_vec(vec![1.0, 2.0]).unwrap().view()),
+        Err(Error::ShapeMismatch { .. })
+    ));
+}
+
+#[test]
+fn solve_triangular() {
+    let tensor_u = Tensor::from_vec(vec![vec![2.0, 1.0], vec![9.0, 4.0]]).unwrap();
+    let tensor_b = Tensor::from_vec(vec![4.0, 8.0]).unwrap();
+
+    /* Only the selected triangle is read */
+    let tensor_x = tensor_u
+        .solve_triangular(&tensor_b.view(), Triangle::Upper, false, false)
+        .unwrap();
+    assert_close(&tensor_x, vec![1.0, 2.0]);
+
+    let tensor_x = tensor_u
+        .solve_triangular(&tensor_b.view(), Triangle::Lower, false, false)
+        .unwrap();
+    assert_close(&tensor_x, vec![2.0, -2.5]);
+
+    let tensor_x = tensor_u
+        .solve_triangular(&tensor_b.view(), Triangle::Upper, true, false)
+        .unwrap();
+    assert_close(&tensor_x, vec![2.0, 1.5]);
+
+    let tensor_x = tensor_u
+        .solve_triangular(&tensor_b.view(), Triangle::Upper, false, true)
+        .unwrap();
+    assert_close(&tensor_x, vec![-4.0, 8.0]);
+
+    let tensor_s = Tensor::from_vec(vec![vec![0.0, 1.0], vec![0.0, 1.0]]).unwrap();
+    assert!(matches!(
+        tensor_s.solve_triangular(&tensor_b.view(), Triangle::Upper, false, false),
+        Err(Error::SingularMatrix { .. })
+    ));
+}
+
+#[test]
+fn solve_banded() {
+    /* [[4, 1, 0, 0], [1, 4, 1, 0], [2, 1, 4, 1], [0, 2, 1, 4]] */
+    let tensor_ab = Tensor::from_vec(vec![
+        vec![0.0, 1.0, 1.0, 1.0],
+        vec![4.0, 4.0, 4.0, 4.0],
+        vec![1.0, 1.0, 1.0, 0.0],
+        vec![2.0, 2.0, 0.0, 0.0],
+    ])
+    .unwrap();
+    let tensor_b = Tensor::from_vec(vec![6.0, 12.0, 20.0, 23.0]).unwrap();
+    let tensor_x = tensor_ab.solve_banded(2, 1, &tensor_b.view()).unwrap();
+    assert_close(&tensor_x, vec![1.0, 2.0, 3.0, 4.0]);
+
+    /* A zero on the diagonal needs pivoting */
+    let tensor_ab = Tensor::from_vec(vec![vec![0.0, 1.0], vec![0.0, 0.0], vec![1.0, 0.0]]).unwrap();
+    let tensor_b = Tensor::from_vec(vec![vec![2.0], vec![3.0]]).unwrap();
+    let tensor_x = tensor_ab.solve_banded(1, 1, &tensor_b.view()).unwrap();
+    assert_close(&tensor_x, vec![3.0, 2.0]);
+
+    assert!(matches!(
+        tensor_ab.solve_banded(1, 0, &tensor_b.view()),
+        Err(Error::InvalidParam { .. })
+    ));
+
+    let tensor_x = Tensor::solve_tridiagonal(
+        &Tensor::from_vec(vec![1.0, 1.0]).unwrap().view(),
+        &Tensor::from_vec(vec![2.0, 2.0, 2.0]).unwrap().view(),
+        &Tensor::from_vec(vec![1.0, 1.0]).unwrap().view(),
+        &Tensor::from_vec(vec![4.0, 8.0, 8.0]).unwrap().view(),
+    )
+    .unwrap();
+    assert_close(&tensor_x, vec![1.0, 2.0, 3.0]);
+
+    let tensor_d = Tensor::from_vec(vec![1.0, 1.0]).unwrap();
+    assert!(matches!(
+        Tensor::solve_tridiagonal(
+            &tensor_d.view(),
+            &tensor_d.view(),
+            &tensor_d.view(),
+            &tensor_d.view()
+        ),
+        Err(Error::ShapeMismatch { .. })
+    ));
+}
@@ -0,0 +1,102 @@
+use assert_close::assert_close;
+use rs_math::tensor::{Error, Tensor};
+
+#[path = "utils/assert_close.rs"]
+mod assert_close;
+
+#[test]
+fn expm() {
+    let tensor_d = Tensor::from_vec(vec![vec![1.0, 0.0], vec![0.0, 2.0]]).unwrap();
+    assert_close(
+        &tensor_d.expm().unwrap(),
+        vec![1f64.exp(), 0.0, 0.0, 2f64.exp()],
+    );
+
+    /* Nilpotent and rotation generators */
+    let tensor_n = Tensor::from_vec(vec![vec![0.0, 1.0], vec![0.0, 0.0]]).unwrap();
+    assert_close(&tensor_n.expm().unwrap(), vec![1.0, 1.0, 0.0, 1.0]);
+
+    let theta = 3.0f64;
+    let tensor_r = Tensor::from_vec(vec![vec![0.0, -theta], vec![theta, 0.0]]).unwrap();
+    assert_close(
+        &tensor_r.expm().unwrap(),
+        vec![theta.cos(), -theta.sin(), theta.sin(), theta.cos()],
+    );
+
+    /* Batched */
+    let tensor_b = Tensor::from_vec(vec![vec![vec![0.0]], vec![vec![1.0]]]).unwrap();
+    assert_close(&tensor_b.expm().unwrap(), vec![1.0, 1f64.exp()]);
+
+    /* Non-finite elements are reported with the index of their matrix */
+    let tensor_b = Tensor::from_vec(vec![vec![vec![0.0]], vec![vec![f64::INFINITY]]]).unwrap();
+    assert!(matches!(
+        tensor_b.expm(),
+        Err(Error::NoConvergence { index }) if index == vec![1]
+    ));
+    let tensor_nan = Tensor::from_vec(vec![vec![f64::NAN, 0.0], vec![0.0, 1.0]]).unwrap();
+    assert!(matches!(
+        tensor_nan.expm(),
+        Err(Error::NoConvergence { .. })
+    ));
+}
+
+#[test]
+fn logm_sqrtm() {
+    let tensor_a = Tensor::from_vec(vec![vec![4.0, 1.0], vec![0.0, 9.0]]).unwrap();
+
+    let tensor_s = tensor_a.sqrtm().unwrap();
+    assert_close(&tensor_s, vec![2.0, 0.2, 0.0, 3.0]);
+
+    let tensor_l = tensor_a.logm().unwrap();
+    assert_close(&tensor_l.expm().unwrap(), tensor_a.data());
+
+    /* Rotations have complex eigenvalues but real roots and logarithms */
+    let theta = 1.2f64;
+    let tensor_r = Tensor::from_vec(vec![
+        vec![theta.cos(), -theta.sin()],
+        vec![theta.sin(), theta.cos()],
+    ])
+    .unwrap();
+    assert_close(&tensor_r.logm().unwrap(), vec![0.0, -theta, theta, 0.0]);
+    let half = theta / 2.0;
+    assert_close(
+        &tensor_r.sqrtm().unwrap(),
+        vec![half.cos(), -half.sin(), half.sin(), half.cos()],
+    );
+
+    let tensor_s = Tensor::from_vec(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+    assert!(matches!(
+        tensor_s.sqrtm(),
+        Err(Error::SingularMatrix { .. })
+    ));
+    assert!(matches!(tensor_s.logm(), Err(Error::SingularMatrix { .. })));
+
+    /* Negative eigenvalues have no real square root */
+    let tensor_n = Tensor::from_vec(vec![vec![-1.0, 0.0], vec![0.0, 1.0]]).unwrap();
+    assert!(tensor_n.sqrtm().is_err());
+}
+
+#[test]
+fn matrix_power() {
+    let tensor_a = Tensor::from_vec(vec![vec![1.0, 1.0], vec![1.0, 0.0]]).unwrap();
+
+    assert_close(&tensor_a.matrix_power(0).unwrap(), vec![1.0, 0.0, 0.0, 1.0]);
+    assert_close(
+        &tensor_a.matrix_power(10).unwrap(),
+        vec![89.0, 55.0, 55.0, 34.0],
+    );
+    assert_close(
+        &tensor_a.matrix_power(-2).unwrap(),
+        vec![1.0, -1.0, -1.0, 2.0],
+    );
+
+    let tensor_s = Tensor::from_vec(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+    assert_close(
+        &tensor_s.matrix_power(2).unwrap(),
+        vec![5.0, 10.0, 10.0, 20.0],
+    );
+    assert!(matches!(
+        tensor_s.matrix_power(-1),
+        Err(Error::SingularMatrix { .. })
+    ));
+}
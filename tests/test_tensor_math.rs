@@ -0,0 +1,111 @@
+use rs_math::tensor::{Complex, Error, Tensor};
+
+#[test]
+fn unary_float() {
+    let tensor = Tensor::from_vec(vec![vec![1.0f64, 4.0], vec![9.0, 16.0]]).unwrap();
+
+    assert_eq!(tensor.sqrt(), vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    assert_eq!(tensor.recip().getval(&[0, 1]).unwrap(), 0.25);
+    assert_eq!(tensor.powi(2), vec![vec![1.0, 16.0], vec![81.0, 256.0]]);
+    assert_eq!(tensor.powf(0.5), tensor.sqrt());
+    assert_eq!(tensor.log2().getval(&[1, 1]).unwrap(), 4.0);
+    assert_eq!(tensor.ln().getval(&[0, 0]).unwrap(), 0.0);
+    assert_eq!(tensor.ln().exp().getval(&[0, 1]).unwrap(), 4.0);
+    assert!((tensor.log10().getval(&[0, 0]).unwrap()).abs() < 1e-12);
+
+    let tensor = Tensor::from_vec(vec![-1.5f32, -0.0, 0.5, 2.5, f32::NAN]).unwrap();
+    let data = tensor.abs().data();
+    assert_eq!(data[..4], [1.5, 0.0, 0.5, 2.5]);
+    assert!(data[4].is_nan());
+
+    assert_eq!(tensor.floor().data()[..4], [-2.0, -0.0, 0.0, 2.0]);
+    assert_eq!(tensor.ceil().data()[..4], [-1.0, 0.0, 1.0, 3.0]);
+    assert_eq!(tensor.round().data()[..4], [-2.0, -0.0, 1.0, 3.0]);
+
+    let data = tensor.sign().data();
+    assert_eq!(data[..4], [-1.0, 0.0, 1.0, 1.0]);
+    assert!(data[4].is_nan());
+
+    let data = tensor.clamp(-1.0, 1.0).unwrap().data();
+    assert_eq!(data[..4], [-1.0, -0.0, 0.5, 1.0]);
+    assert!(data[4].is_nan());
+
+    assert!(matches!(
+        tensor.clamp(1.0, -1.0),
+        Err(Error::InvalidParam { .. })
+    ));
+}
+
+#[test]
+fn unary_trig() {
+    let pi = std::f64::consts::PI;
+    let tensor = Tensor::from_vec(vec![0.0, pi / 2.0, pi]).unwrap();
+
+    let close = |a: Vec<f64>, b: Vec<f64>| a.iter().zip(&b).all(|(a, b)| (a - b).abs() < 1e-12);
+    assert!(close(tensor.sin().data(), vec![0.0, 1.0, 0.0]));
+    assert!(close(tensor.cos().data(), vec![1.0, 0.0, -1.0]));
+    assert!(close(
+        tensor.map(|val| val / 4.0).tan().data(),
+        vec![0.0, (pi / 8.0).tan(), 1.0]
+    ));
+    assert!(close(
+        tensor.tanh().data(),
+        vec![0.0, (pi / 2.0).tanh(), pi.tanh()]
+    ));
+}
+
+#[test]
+fn unary_inplace() {
+    let mut tensor = Tensor::from_vec(vec![vec![1.0f64, -2.0], vec![3.0, -4.0]]).unwrap();
+    tensor.abs_();
+    tensor.powi_(2);
+    tensor.sqrt_();
+    assert_eq!(tensor, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+
+    tensor.clamp_(1.5, 3.5).unwrap();
+    assert_eq!(tensor, vec![vec![1.5, 2.0], vec![3.0, 3.5]]);
+
+    /* Only the viewed elements are changed */
+    let mut view = tensor.slice_mut(&[1]).unwrap();
+    view.recip_();
+    view.sign_();
+    assert_eq!(view.data(), vec![1.0, 1.0]);
+}
+
+#[test]
+fn map_zip_with() {
+    let tensor = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+
+    let tensor_t = tensor.transpose().unwrap();
+    assert_eq!(
+        tensor_t.map(|val| val as f32 / 2.0),
+        vec![vec![0.5, 2.0], vec![1.0, 2.5], vec![1.5, 3.0]]
+    );
+    assert_eq!(
+        tensor.map(|val| val % 2 == 0),
+        vec![vec![false, true, false], vec![true, false, true]]
+    );
+
+    let mut tensor_m = tensor.clone();
+    tensor_m.map_inplace(|val| val * val);
+    assert_eq!(tensor_m, vec![vec![1, 4, 9], vec![16, 25, 36]]);
+
+    /* zip_with broadcasts and may mix element types */
+    let scale = Tensor::from_vec(vec![0.5f64, 2.0, -1.0]).unwrap();
+    assert_eq!(
+        tensor.zip_with(&scale.view(), |a, b| a as f64 * b).unwrap(),
+        vec![vec![0.5, 4.0, -3.0], vec![2.0, 10.0, -6.0]]
+    );
+
+    assert!(matches!(
+        tensor.zip_with(&Tensor::from_vec(vec![1, 2]).unwrap().view(), |a, b| a + b),
+        Err(Error::ShapeMismatchBroadcast { .. })
+    ));
+}
+
+#[test]
+fn abs_complex() {
+    let tensor =
+        Tensor::from_vec(vec![Complex::new(3.0f32, -4.0), Complex::new(0.0, 1.0)]).unwrap();
+    assert_eq!(tensor.abs(), vec![5.0, 1.0]);
+}
@@ -0,0 +1,447 @@
+use rs_math::s;
+use rs_math::tensor::{Error, Norm, Tensor};
+
+#[path = "utils/ndim_vec.rs"]
+mod ndim_vec;
+
+#[test]
+fn sum() {
+    let tensor_2x3 = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    assert_eq!(tensor_2x3.sum(), 21);
+
+    // Non-contiguous view
+    assert_eq!(tensor_2x3.t().unwrap().sum(), 21);
+
+    // Sliced view
+    assert_eq!(tensor_2x3.slice(&[1]).unwrap().sum(), 15);
+}
+
+#[test]
+fn sum_axis() {
+    let tensor_2x3x4 = Tensor::from_vec(ndim_vec::ndim_vec_3d::<i32>(&[2, 3, 4], false)).unwrap();
+
+    /* Single axis */
+
+    let tensor = tensor_2x3x4.sum_axis(&[0], false).unwrap();
+    assert_eq!(tensor.shape(), vec![3, 4]);
+    assert_eq!(
+        tensor,
+        vec![
+            vec![14, 16, 18, 20],
+            vec![22, 24, 26, 28],
+            vec![30, 32, 34, 36]
+        ]
+    );
+
+    let tensor = tensor_2x3x4.sum_axis(&[2], false).unwrap();
+    assert_eq!(tensor.shape(), vec![2, 3]);
+    assert_eq!(tensor, vec![vec![10, 26, 42], vec![58, 74, 90]]);
+
+    /* Multiple axes */
+
+    let tensor = tensor_2x3x4.sum_axis(&[0, 2], false).unwrap();
+    assert_eq!(tensor.shape(), vec![3]);
+    assert_eq!(tensor, vec![68, 100, 132]);
+
+    /* All axes: 0-d tensor */
+
+    let tensor = tensor_2x3x4.sum_axis(&[2, 1, 0], false).unwrap();
+    assert_eq!(tensor.shape(), Vec::<usize>::new());
+    assert_eq!(tensor.getval(&[]).unwrap(), 300);
+
+    /* Keep dimensions */
+
+    let tensor = tensor_2x3x4.sum_axis(&[1], true).unwrap();
+    assert_eq!(tensor.shape(), vec![2, 1, 4]);
+    assert_eq!(
+        tensor,
+        vec![vec![vec![15, 18, 21, 24]], vec![vec![51, 54, 57, 60]]]
+    );
+
+    /* Non-contiguous view */
+
+    let view_4x3x2 = tensor_2x3x4.permute(&[2, 1, 0]).unwrap();
+    let tensor = view_4x3x2.sum_axis(&[2], false).unwrap();
+    assert_eq!(tensor.shape(), vec![4, 3]);
+    assert_eq!(
+        tensor,
+        vec![
+            vec![14, 22, 30],
+            vec![16, 24, 32],
+            vec![18, 26, 34],
+            vec![20, 28, 36]
+        ]
+    );
+
+    /* Sliced view */
+
+    let view_3x4 = tensor_2x3x4.slice(&[1]).unwrap();
+    assert_eq!(view_3x4.sum_axis(&[1], false).unwrap(), vec![58, 74, 90]);
+
+    /* Negative: Invalid axis */
+
+    assert!(matches!(
+        tensor_2x3x4.sum_axis(&[3], false),
+        Err(Error::InvalidAxis { .. })
+    ));
+
+    /* Negative: Repeated axis */
+
+    assert!(matches!(
+        tensor_2x3x4.sum_axis(&[1, 1], false),
+        Err(Error::InvalidParam { .. })
+    ));
+}
+
+#[test]
+fn prod_axis() {
+    let tensor_2x3 = Tensor::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    assert_eq!(tensor_2x3.prod(), 720);
+    assert_eq!(tensor_2x3.prod_axis(&[0], false).unwrap(), vec![4, 10, 18]);
+    assert_eq!(
+        tensor_2x3.prod_axis(&[1], true).unwrap(),
+        vec![vec![6], vec![120]]
+    );
+}
+
+#[test]
+fn max_axis() {
+    let tensor_2x3 = Tensor::from_vec(vec![vec![1, 9, 3], vec![7, 5, 6]]).unwrap();
+    assert_eq!(tensor_2x3.max_axis(&[0], false).unwrap(), vec![7, 9, 6]);
+    assert_eq!(tensor_2x3.max_axis(&[1], false).unwrap(), vec![9, 7]);
+    assert_eq!(tensor_2x3.max_axis(&[0, 1], true).unwrap(), vec![vec![9]]);
+
+    // Non-contiguous view
+    assert_eq!(
+        tensor_2x3.t().unwrap().max_axis(&[1], false).unwrap(),
+        vec![7, 9, 6]
+    );
+}
+
+#[test]
+fn min_axis() {
+    let tensor_2x3 = Tensor::from_vec(vec![vec![1.5, 9.0, 3.0], vec![-7.0, 5.0, 6.0]]).unwrap();
+    assert_eq!(
+        tensor_2x3.min_axis(&[0], false).unwrap(),
+        vec![-7.0, 5.0, 3.0]
+    );
+    assert_eq!(tensor_2x3.min_axis(&[1], false).unwrap(), vec![1.5, -7.0]);
+}
+
+#[test]
+fn max_min() {
+    let tensor_2x3 = Tensor::from_vec(vec![vec![1, 9, 3], vec![-7, 5, 6]]).unwrap();
+    assert_eq!(tensor_2x3.max().unwrap(), 9);
+    assert_eq!(tensor_2x3.min().unwrap(), -7);
+    assert_eq!(
+        tensor_2x3.t().unwrap().slice(&[2]).unwrap().max().unwrap(),
+        6
+    );
+
+    let inf = f64::INFINITY;
+    let tensor_inf = Tensor::from_vec(vec![-inf, -inf, -inf]).unwrap();
+    assert_eq!(tensor_inf.max().unwrap(), -inf);
+    let tensor_inf = Tensor::from_vec(vec![inf, inf]).unwrap();
+    assert_eq!(tensor_inf.min().unwrap(), inf);
+
+    /* NaN propagates, as in max_axis and min_axis */
+    let tensor_nan = Tensor::from_vec(vec![1.0, f64::NAN, 3.0]).unwrap();
+    assert!(tensor_nan.max().unwrap().is_nan());
+    assert!(tensor_nan.min().unwrap().is_nan());
+
+    let tensor_0x3 = tensor_2x3.slice_info(&s![-1..1]).unwrap();
+    assert!(matches!(tensor_0x3.max(), Err(Error::InvalidParam { .. })));
+    let tensor_empty = Tensor::<f64>::from_shape(&[0], vec![]).unwrap();
+    assert!(matches!(
+        tensor_empty.min(),
+        Err(Error::InvalidParam { .. })
+    ));
+}
+
+#[test]
+fn max_min_axis_non_finite() {
+    let inf = f32::INFINITY;
+
+    /* Lanes start from their first element, not the type's extreme value */
+    let tensor_2x2 = Tensor::from_vec(vec![vec![-inf, inf], vec![-inf, inf]]).unwrap();
+    assert_eq!(tensor_2x2.max_axis(&[0], false).unwrap(), vec![-inf, inf]);
+    assert_eq!(tensor_2x2.min_axis(&[0], false).unwrap(), vec![-inf, inf]);
+
+    /* NaN propagates, as in argmax and argmin */
+    let tensor_2x3 = Tensor::from_vec(vec![vec![1.0, f32::NAN, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+    let max = tensor_2x3.max_axis(&[1], false).unwrap();
+    assert!(max.getval(&[0]).unwrap().is_nan());
+    assert_eq!(max.getval(&[1]).unwrap(), 6.0);
+    assert_eq!(tensor_2x3.argmax(1).unwrap(), vec![1, 2]);
+    let min = tensor_2x3.min_axis(&[0], false).unwrap();
+    assert_eq!(min.getval(&[0]).unwrap(), 1.0);
+    assert!(min.getval(&[1]).unwrap().is_nan());
+    assert!(tensor_2x3
+        .max_axis(&[0, 1], false)
+        .unwrap()
+        .getval(&[])
+        .unwrap()
+        .is_nan());
+
+    /* Empty reduced axes have no maximum or minimum */
+    let tensor_0x3 = tensor_2x3.slice_info(&s![-1..1]).unwrap();
+    assert_eq!(tensor_0x3.shape(), vec![0, 3]);
+    assert!(matches!(
+        tensor_0x3.max_axis(&[0], false),
+        Err(Error::InvalidParam { .. })
+    ));
+    assert!(matches!(
+        tensor_0x3.min_axis(&[0, 1], true),
+        Err(Error::InvalidParam { .. })
+    ));
+    assert_eq!(tensor_0x3.max_axis(&[1], false).unwrap().shape(), vec![0]);
+}
+
+#[test]
+fn mean_axis() {
+    let tensor_2x3 = Tensor::from_vec(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+    assert_eq!(tensor_2x3.mean(), 3.5);
+    assert_eq!(
+        tensor_2x3.mean_axis(&[0], false).unwrap(),
+        vec![2.5, 3.5, 4.5]
+    );
+    assert_eq!(tensor_2x3.mean_axis(&[1], false).unwrap(), vec![2.0, 5.0]);
+    assert_eq!(
+        tensor_2x3
+            .mean_axis(&[0, 1], false)
+            .unwrap()
+            .getval(&[])
+            .unwrap(),
+        3.5
+    );
+
+    /* Keep dimensions: The result broadcasts back against the input */
+
+    let mean = tensor_2x3.mean_axis(&[1], true).unwrap();
+    assert_eq!(mean.shape(), vec![2, 1]);
+    assert_eq!(
+        tensor_2x3.sub(&mean.view()).unwrap(),
+        vec![vec![-1.0, 0.0, 1.0], vec![-1.0, 0.0, 1.0]]
+    );
+}
+
+#[test]
+fn argmax() {
+    let tensor_2x3 = Tensor::from_vec(vec![vec![1, 9, 3], vec![7, 5, 7]]).unwrap();
+    assert_eq!(tensor_2x3.argmax(0).unwrap(), vec![1u64, 0, 1]);
+    assert_eq!(tensor_2x3.argmax(1).unwrap(), vec![1u64, 0]);
+
+    // Non-contiguous view
+    assert_eq!(tensor_2x3.t().unwrap().argmax(0).unwrap(), vec![1u64, 0]);
+
+    /* Softmax output: Class per sample */
+
+    let probs_3x4 = Tensor::from_vec(vec![
+        vec![0.1f32, 0.6, 0.2, 0.1],
+        vec![0.7, 0.1, 0.1, 0.1],
+        vec![0.2, 0.2, 0.1, 0.5],
+    ])
+    .unwrap();
+    assert_eq!(probs_3x4.argmax(1).unwrap(), vec![1u64, 0, 3]);
+
+    /* NaN propagates */
+
+    let tensor_2x3 =
+        Tensor::from_vec(vec![vec![1.0, f64::NAN, 3.0], vec![4.0, 5.0, f64::NAN]]).unwrap();
+    assert_eq!(tensor_2x3.argmax(1).unwrap(), vec![1u64, 2]);
+
+    /* Negative: Invalid axis */
+
+    assert!(matches!(
+        tensor_2x3.argmax(2),
+        Err(Error::InvalidAxis { .. })
+    ));
+}
+
+#[test]
+fn argmin() {
+    let tensor_2x3x2 = Tensor::from_vec(vec![
+        vec![vec![4, 2], vec![1, 8], vec![1, 3]],
+        vec![vec![0, 5], vec![6, 5], vec![9, 7]],
+    ])
+    .unwrap();
+
+    let tensor = tensor_2x3x2.argmin(1).unwrap();
+    assert_eq!(tensor.shape(), vec![2, 2]);
+    assert_eq!(tensor, vec![vec![1u64, 0], vec![0, 0]]);
+
+    /* NaN propagates */
+
+    let tensor_3 = Tensor::from_vec(vec![1.0, f32::NAN, -3.0]).unwrap();
+    assert_eq!(tensor_3.argmin(0).unwrap().getval(&[]).unwrap(), 1);
+}
+
+#[test]
+fn topk() {
+    let tensor_2x5 = Tensor::from_vec(vec![vec![3, 9, 1, 9, 4], vec![0, 2, 8, 6, 5]]).unwrap();
+
+    /* Largest along the last axis */
+
+    let (values, indices) = tensor_2x5.topk(3, 1, true).unwrap();
+    assert_eq!(values.shape(), vec![2, 3]);
+    assert_eq!(values, vec![vec![9, 9, 4], vec![8, 6, 5]]);
+    assert_eq!(indices, vec![vec![1u64, 3, 4], vec![2, 3, 4]]);
+
+    /* Smallest along the first axis */
+
+    let (values, indices) = tensor_2x5.topk(1, 0, false).unwrap();
+    assert_eq!(values.shape(), vec![1, 5]);
+    assert_eq!(values, vec![vec![0, 2, 1, 6, 4]]);
+    assert_eq!(indices, vec![vec![1u64, 1, 0, 1, 0]]);
+
+    /* NaN is ordered above every other value */
+
+    let tensor_4 = Tensor::from_vec(vec![1.0, f32::NAN, 3.0, 2.0]).unwrap();
+    let (values, indices) = tensor_4.topk(2, 0, true).unwrap();
+    assert!(values.getval(&[0]).unwrap().is_nan());
+    assert_eq!(values.getval(&[1]).unwrap(), 3.0);
+    assert_eq!(indices, vec![1u64, 2]);
+
+    let (values, indices) = tensor_4.topk(2, 0, false).unwrap();
+    assert_eq!(values, vec![1.0, 2.0]);
+    assert_eq!(indices, vec![0u64, 3]);
+
+    /* Negative: k exceeds the axis size */
+
+    assert!(matches!(
+        tensor_2x5.topk(3, 0, true),
+        Err(Error::InvalidParam { .. })
+    ));
+}
+
+#[test]
+fn mean_compensated() {
+    // Naive f32 accumulation of 1M values drifts by ~1%
+    let tensor = Tensor::from_shape(&[1000, 1000], vec![0.1f32; 1_000_000]).unwrap();
+    assert!((tensor.mean() - 0.1).abs() < 1e-6);
+    assert!(tensor
+        .mean_axis(&[0, 1], false)
+        .unwrap()
+        .iter()
+        .all(|val| (val - 0.1).abs() < 1e-6));
+
+    // Infinity is preserved
+    let tensor_3 = Tensor::from_vec(vec![f32::INFINITY, 1.0, 2.0]).unwrap();
+    assert_eq!(tensor_3.mean(), f32::INFINITY);
+}
+
+#[test]
+fn var() {
+    let tensor_2x4 =
+        Tensor::from_vec(vec![vec![2.0f64, 4.0, 4.0, 4.0], vec![5.0, 5.0, 7.0, 9.0]]).unwrap();
+
+    /* All elements */
+
+    assert_eq!(tensor_2x4.var(0), 4.0);
+    assert_eq!(tensor_2x4.std(0), 2.0);
+    assert!((tensor_2x4.var(1) - 32.0 / 7.0).abs() < 1e-12);
+
+    /* Over an axis */
+
+    let tensor = tensor_2x4.var_axis(&[1], 0, false).unwrap();
+    assert_eq!(tensor, vec![0.75, 2.75]);
+
+    let tensor = tensor_2x4.var_axis(&[0], 1, true).unwrap();
+    assert_eq!(tensor.shape(), vec![1, 4]);
+    assert_eq!(tensor, vec![vec![4.5, 0.5, 4.5, 12.5]]);
+
+    let tensor = tensor_2x4.std_axis(&[0], 0, false).unwrap();
+    assert_eq!(tensor, vec![1.5, 0.5, 1.5, 2.5]);
+
+    // Non-contiguous view
+    assert_eq!(
+        tensor_2x4.t().unwrap().var_axis(&[0], 0, false).unwrap(),
+        vec![0.75, 2.75]
+    );
+
+    /* Degrees of freedom exceed the number of elements */
+
+    assert!(tensor_2x4
+        .var_axis(&[0], 2, false)
+        .unwrap()
+        .iter()
+        .all(|val| val.is_nan()));
+
+    /* Large offset: Two-pass algorithm keeps the precision */
+
+    let tensor_4 = Tensor::from_vec(vec![1e6f32 + 4.0, 1e6 + 7.0, 1e6 + 13.0, 1e6 + 16.0]).unwrap();
+    assert_eq!(tensor_4.var(0), 22.5);
+}
+
+#[test]
+fn logsumexp() {
+    let tensor_2x3 =
+        Tensor::from_vec(vec![vec![1.0f64, 2.0, 3.0], vec![1000.0, 1000.0, 1000.0]]).unwrap();
+
+    let expected = (1f64.exp() + 2f64.exp() + 3f64.exp()).ln();
+    let tensor = tensor_2x3.logsumexp_axis(&[1], false).unwrap();
+    assert!((tensor.getval(&[0]).unwrap() - expected).abs() < 1e-12);
+
+    // No overflow for large inputs
+    assert!((tensor.getval(&[1]).unwrap() - (1000.0 + 3f64.ln())).abs() < 1e-12);
+
+    // Keep dimensions
+    let tensor = tensor_2x3.logsumexp_axis(&[1], true).unwrap();
+    assert_eq!(tensor.shape(), vec![2, 1]);
+
+    /* All elements */
+
+    let tensor_3 = Tensor::from_vec(vec![f32::NEG_INFINITY, 0.0, 0.0]).unwrap();
+    assert!((tensor_3.logsumexp() - 2f32.ln()).abs() < 1e-6);
+
+    let tensor_2 = Tensor::from_vec(vec![f32::NEG_INFINITY, f32::NEG_INFINITY]).unwrap();
+    assert_eq!(tensor_2.logsumexp(), f32::NEG_INFINITY);
+
+    let tensor_2 = Tensor::from_vec(vec![f32::INFINITY, 1.0]).unwrap();
+    assert_eq!(tensor_2.logsumexp(), f32::INFINITY);
+}
+
+#[test]
+fn norm() {
+    let tensor_2x2 = Tensor::from_vec(vec![vec![3.0f64, -4.0], vec![-12.0, 0.0]]).unwrap();
+
+    /* All elements */
+
+    assert_eq!(tensor_2x2.norm(Norm::L1), 19.0);
+    assert_eq!(tensor_2x2.norm(Norm::L2), 13.0);
+    assert_eq!(tensor_2x2.norm(Norm::Linf), 12.0);
+    assert_eq!(tensor_2x2.norm(Norm::Fro), 13.0);
+
+    /* Over an axis */
+
+    assert_eq!(
+        tensor_2x2.norm_axis(Norm::L2, &[1], false).unwrap(),
+        vec![5.0, 12.0]
+    );
+    assert_eq!(
+        tensor_2x2.norm_axis(Norm::L1, &[0], true).unwrap(),
+        vec![vec![15.0, 4.0]]
+    );
+    assert_eq!(
+        tensor_2x2.norm_axis(Norm::Linf, &[0], false).unwrap(),
+        vec![12.0, 4.0]
+    );
+
+    /* Frobenius norm over a batch of matrices */
+
+    let tensor_2x2x2 = Tensor::from_vec(vec![
+        vec![vec![3.0f64, -4.0], vec![-12.0, 0.0]],
+        vec![vec![1.0, 1.0], vec![1.0, 1.0]],
+    ])
+    .unwrap();
+    assert_eq!(
+        tensor_2x2x2.norm_axis(Norm::Fro, &[1, 2], false).unwrap(),
+        vec![13.0, 2.0]
+    );
+
+    // Negative: Frobenius norm expects two axes
+    assert!(matches!(
+        tensor_2x2x2.norm_axis(Norm::Fro, &[0], false),
+        Err(Error::InvalidParam { .. })
+    ));
+}
@@ -0,0 +1,114 @@
+use rs_math::s;
+use rs_math::tensor::{Error, NewAxis, SliceInfo, Tensor};
+
+#[path = "utils/ndim_vec.rs"]
+mod ndim_vec;
+
+#[test]
+fn slice_info() {
+    let tensor_4x5 = Tensor::from_vec(ndim_vec::ndim_vec_2d::<i32>(&[4, 5], false)).unwrap();
+
+    let view = tensor_4x5.slice_info(&s![1..3, ..;2]).unwrap();
+    assert_eq!(view.shape(), vec![2, 3]);
+    assert_eq!(view, vec![vec![6, 8, 10], vec![11, 13, 15]]);
+
+    /* Negative indices and bounds count from the end */
+    let view = tensor_4x5.slice_info(&s![-1, -3..]).unwrap();
+    assert_eq!(view, vec![18, 19, 20]);
+
+    let view = tensor_4x5.slice_info(&s![..-2, 4]).unwrap();
+    assert_eq!(view, vec![5, 10]);
+
+    /* Missing trailing axes are kept whole */
+    let view = tensor_4x5.slice_info(&s![2]).unwrap();
+    assert_eq!(view, vec![11, 12, 13, 14, 15]);
+
+    /* Out of range bounds are clipped */
+    let view = tensor_4x5.slice_info(&s![3..10, 1..=2]).unwrap();
+    assert_eq!(view, vec![vec![17, 18]]);
+
+    let view = tensor_4x5.slice_info(&s![-1..1]).unwrap();
+    assert_eq!(view.shape(), vec![0, 5]);
+}
+
+#[test]
+fn slice_info_3d() {
+    let tensor_2x3x4 = Tensor::from_vec(ndim_vec::ndim_vec_3d::<i32>(&[2, 3, 4], false)).unwrap();
+
+    let view = tensor_2x3x4.slice_info(&s![.., 1, 1..;2]).unwrap();
+    assert_eq!(view.shape(), vec![2, 2]);
+    assert_eq!(view, vec![vec![6, 8], vec![18, 20]]);
+
+    let view = tensor_2x3x4.slice_info(&s![1.., ..;2, -1]).unwrap();
+    assert_eq!(view, vec![vec![16, 24]]);
+}
+
+#[test]
+fn slice_info_new_axis() {
+    let tensor_2x3 = Tensor::from_vec(ndim_vec::ndim_vec_2d::<i32>(&[2, 3], false)).unwrap();
+
+    let view = tensor_2x3.slice_info(&s![NewAxis, .., 1..]).unwrap();
+    assert_eq!(view.shape(), vec![1, 2, 2]);
+    assert_eq!(view, vec![vec![vec![2, 3], vec![5, 6]]]);
+
+    let view = tensor_2x3.slice_info(&s![1, .., NewAxis]).unwrap();
+    assert_eq!(view.shape(), vec![3, 1]);
+    assert_eq!(view, vec![vec![4], vec![5], vec![6]]);
+}
+
+#[test]
+fn slice_info_builder() {
+    let tensor_3x4 = Tensor::from_vec(ndim_vec::ndim_vec_2d::<i32>(&[3, 4], false)).unwrap();
+
+    let info = SliceInfo::new().range_step(.., 2).range(-2..).new_axis();
+    assert_eq!(info, s![..;2, -2.., NewAxis]);
+
+    let view = tensor_3x4.slice_info(&info).unwrap();
+    assert_eq!(view.shape(), vec![2, 2, 1]);
+    assert_eq!(view, vec![vec![vec![3], vec![4]], vec![vec![11], vec![12]]]);
+
+    /* Slicing a view keeps its offset and strides */
+    let view_t = tensor_3x4.transpose().unwrap();
+    let view = view_t
+        .slice_info(&SliceInfo::new().index(1).full())
+        .unwrap();
+    assert_eq!(view, vec![2, 6, 10]);
+
+    let sub = tensor_3x4.slice(&[1]).unwrap();
+    let view = sub.slice_info(&s![1..;2]).unwrap();
+    assert_eq!(view, vec![6, 8]);
+}
+
+#[test]
+fn slice_info_mut() {
+    let mut tensor_3x4 = Tensor::from_vec(ndim_vec::ndim_vec_2d::<i32>(&[3, 4], false)).unwrap();
+
+    let mut view = tensor_3x4.slice_info_mut(&s![.., -1]).unwrap();
+    for val in view.iter_mut() {
+        *val = 0;
+    }
+    assert_eq!(view.data(), vec![0, 0, 0]);
+    assert_eq!(view.nelems(), 3);
+}
+
+#[test]
+fn slice_info_errors() {
+    let tensor_2x3 = Tensor::from_vec(ndim_vec::ndim_vec_2d::<i32>(&[2, 3], false)).unwrap();
+
+    assert!(matches!(
+        tensor_2x3.slice_info(&s![0, 0, 0]),
+        Err(Error::DimensionMismatch { .. })
+    ));
+    assert!(matches!(
+        tensor_2x3.slice_info(&s![-3]),
+        Err(Error::InvalidParam { .. })
+    ));
+    assert!(matches!(
+        tensor_2x3.slice_info(&s![.., 3]),
+        Err(Error::InvalidParam { .. })
+    ));
+    assert!(matches!(
+        tensor_2x3.slice_info(&s![..;0]),
+        Err(Error::InvalidParam { .. })
+    ));
+}
@@ -6,6 +6,36 @@ fn from_zeros() {
     assert_eq!(vector, vec![vec![0], vec![0], vec![0]]);
 }
 
+#[test]
+fn ones_full() {
+    let vector = Vector::<u8>::ones(2).unwrap();
+    assert_eq!(vector, vec![vec![1], vec![1]]);
+
+    let vector = Vector::full(3, -1).unwrap();
+    assert_eq!(vector, vec![vec![-1], vec![-1], vec![-1]]);
+}
+
+#[test]
+fn from_fn() {
+    let vector = Vector::from_fn(3, |i| (i * i) as i64).unwrap();
+    assert_eq!(vector, vec![vec![0], vec![1], vec![4]]);
+}
+
+#[test]
+fn arange() {
+    let vector = Vector::arange(1, 7, 2).unwrap();
+    assert_eq!(vector, vec![vec![1], vec![3], vec![5]]);
+}
+
+#[test]
+fn linspace() {
+    let vector = Vector::linspace(-1.0, 1.0, 3).unwrap();
+    assert_eq!(vector, vec![vec![-1.0], vec![0.0], vec![1.0]]);
+
+    let vector = Vector::logspace(0.0, 2.0, 3, 10.0).unwrap();
+    assert_eq!(vector, vec![vec![1.0], vec![10.0], vec![100.0]]);
+}
+
 #[test]
 fn from_vec() {
     let vector = Vector::from_vec(vec![1, 2, 3]).unwrap();
@@ -0,0 +1,10 @@
+use rs_math::tensor::Tensor;
+
+/// Asserts that `tensor` holds `expected` up to an absolute error of 1e-10.
+pub fn assert_close(tensor: &Tensor<f64>, expected: Vec<f64>) {
+    let data = tensor.data();
+    assert_eq!(data.len(), expected.len());
+    for (val, exp) in data.iter().zip(&expected) {
+        assert!((val - exp).abs() < 1e-10, "{:?} != {:?}", data, expected);
+    }
+}
@@ -31,7 +31,7 @@ where
 {
     let shape = py_array.shape().to_vec();
     let data = py_array.readonly().as_slice().unwrap().to_vec();
-    Tensor::from_shape(&shape, &data)
+    Tensor::from_shape(&shape, data)
 }
 
 pub fn assert_eq<'a, 'py, U, S>(tensor: &TensorBase<'a, U, S>, py_array: &Bound<'py, PyArrayDyn<U>>)